@@ -0,0 +1,17 @@
+CREATE TABLE guild_settings (
+    server_id BIGINT NOT NULL PRIMARY KEY,
+
+    language TEXT,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    modified_at INTEGER
+);
+
+CREATE TABLE user_settings (
+    user_id BIGINT NOT NULL PRIMARY KEY,
+
+    language TEXT,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    modified_at INTEGER
+);
@@ -0,0 +1,6 @@
+ALTER TABLE guild_settings ADD COLUMN embed_color INTEGER;
+ALTER TABLE guild_settings ADD COLUMN timezone TEXT;
+ALTER TABLE guild_settings ADD COLUMN ephemeral BOOLEAN;
+ALTER TABLE guild_settings ADD COLUMN custom_emojis BOOLEAN;
+ALTER TABLE guild_settings ADD COLUMN market_orders_limit INTEGER;
+ALTER TABLE guild_settings ADD COLUMN market_ingame_only BOOLEAN;
@@ -0,0 +1,4 @@
+ALTER TABLE user_settings ADD COLUMN timezone TEXT;
+ALTER TABLE user_settings ADD COLUMN time_format TEXT;
+
+ALTER TABLE guild_settings ADD COLUMN time_format TEXT;
@@ -0,0 +1,11 @@
+CREATE TABLE arbitration_criteria (
+    server_id BIGINT NOT NULL PRIMARY KEY,
+
+    mission_type TEXT,
+    faction TEXT,
+    planet TEXT,
+    node TEXT,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    modified_at INTEGER
+);
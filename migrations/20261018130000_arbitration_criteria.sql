@@ -0,0 +1,2 @@
+-- NULL keeps the previous behaviour of only notifying about S-Tier arbitrations
+ALTER TABLE arbitration_criteria ADD minimum_tier TEXT;
@@ -0,0 +1,9 @@
+CREATE TABLE reward_filters (
+    server_id BIGINT NOT NULL,
+    subscription_type INTEGER NOT NULL,
+    reward TEXT NOT NULL COLLATE NOCASE,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    PRIMARY KEY(server_id, subscription_type, reward)
+);
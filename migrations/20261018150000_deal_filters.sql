@@ -0,0 +1,8 @@
+CREATE TABLE deal_filters (
+    server_id BIGINT NOT NULL PRIMARY KEY,
+
+    minimum_discount INTEGER NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    modified_at INTEGER
+);
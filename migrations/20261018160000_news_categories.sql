@@ -0,0 +1,8 @@
+CREATE TABLE news_categories (
+    server_id BIGINT NOT NULL,
+    category INTEGER NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    PRIMARY KEY(server_id, category)
+);
@@ -0,0 +1,11 @@
+CREATE TABLE event_messages (
+    server_id BIGINT NOT NULL,
+    event_id TEXT NOT NULL,
+
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    PRIMARY KEY(server_id, event_id)
+);
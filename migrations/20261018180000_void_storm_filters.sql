@@ -0,0 +1,9 @@
+CREATE TABLE void_storm_filters (
+    server_id BIGINT NOT NULL PRIMARY KEY,
+
+    tier INTEGER,
+    region INTEGER,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+    modified_at INTEGER
+);
@@ -0,0 +1,24 @@
+CREATE TABLE lfg_squads (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    server_id BIGINT NOT NULL,
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT,
+    owner_id BIGINT NOT NULL,
+
+    activity INTEGER NOT NULL,
+    note TEXT,
+    starts_at INTEGER NOT NULL,
+    slots INTEGER NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE TABLE lfg_members (
+    squad_id INTEGER NOT NULL REFERENCES lfg_squads(id) ON DELETE CASCADE,
+    user_id BIGINT NOT NULL,
+
+    joined_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL,
+
+    PRIMARY KEY(squad_id, user_id)
+);
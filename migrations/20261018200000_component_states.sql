@@ -0,0 +1,8 @@
+CREATE TABLE component_states (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    user_id BIGINT NOT NULL,
+    state TEXT NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
@@ -0,0 +1,17 @@
+CREATE TABLE reminders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    user_id BIGINT NOT NULL,
+    server_id BIGINT,
+    -- NULL means the reminder is sent as a DM
+    channel_id BIGINT,
+
+    event INTEGER NOT NULL,
+    detail TEXT,
+    event_at INTEGER NOT NULL,
+    remind_at INTEGER NOT NULL,
+
+    created_at INTEGER DEFAULT CURRENT_TIMESTAMP NOT NULL
+);
+
+CREATE INDEX reminders_remind_at ON reminders(remind_at);
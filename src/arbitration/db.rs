@@ -0,0 +1,159 @@
+use std::{collections::HashMap, future::Future};
+
+use sqlx::SqlitePool;
+
+use crate::{
+    arbitration::{ArbitrationCriteria, ArbitrationFilter},
+    commands::arbi::UserArbitrationTier,
+};
+
+pub trait ArbitrationDbExtension {
+    fn get_arbitration_criteria(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Option<ArbitrationCriteria>, sqlx::Error>> + Send;
+
+    /// The criteria of every guild that has configured them, keyed by guild id.
+    fn get_all_arbitration_criteria(
+        &self,
+    ) -> impl Future<Output = Result<HashMap<i64, ArbitrationCriteria>, sqlx::Error>> + Send;
+
+    fn save_arbitration_criteria(
+        &self,
+        guild_id: i64,
+        criteria: &ArbitrationCriteria,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_arbitration_criteria(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl ArbitrationDbExtension for SqlitePool {
+    async fn get_arbitration_criteria(
+        &self,
+        guild_id: i64,
+    ) -> Result<Option<ArbitrationCriteria>, sqlx::Error> {
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                minimum_tier as "minimum_tier: UserArbitrationTier",
+                mission_type,
+                faction,
+                planet,
+                node
+            FROM arbitration_criteria
+            WHERE server_id = $1
+            "#,
+            guild_id
+        )
+        .fetch_optional(self)
+        .await?;
+
+        Ok(row.map(|row| ArbitrationCriteria {
+            minimum_tier: row.minimum_tier,
+            filter: ArbitrationFilter {
+                mission_type: row.mission_type,
+                faction: row.faction,
+                planet: row.planet,
+                node: row.node,
+            },
+        }))
+    }
+
+    async fn get_all_arbitration_criteria(
+        &self,
+    ) -> Result<HashMap<i64, ArbitrationCriteria>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                server_id,
+                minimum_tier as "minimum_tier: UserArbitrationTier",
+                mission_type,
+                faction,
+                planet,
+                node
+            FROM arbitration_criteria
+            "#
+        )
+        .fetch_all(self)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let criteria = ArbitrationCriteria {
+                    minimum_tier: row.minimum_tier,
+                    filter: ArbitrationFilter {
+                        mission_type: row.mission_type,
+                        faction: row.faction,
+                        planet: row.planet,
+                        node: row.node,
+                    },
+                };
+                (row.server_id, criteria)
+            })
+            .collect())
+    }
+
+    async fn save_arbitration_criteria(
+        &self,
+        guild_id: i64,
+        criteria: &ArbitrationCriteria,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            INSERT INTO arbitration_criteria (
+                server_id,
+                minimum_tier,
+                mission_type,
+                faction,
+                planet,
+                node
+            )
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (server_id)
+            DO UPDATE
+            SET modified_at = CURRENT_TIMESTAMP,
+                minimum_tier = $2,
+                mission_type = $3,
+                faction = $4,
+                planet = $5,
+                node = $6;
+            ",
+            guild_id,
+            criteria.minimum_tier,
+            criteria.filter.mission_type,
+            criteria.filter.faction,
+            criteria.filter.planet,
+            criteria.filter.node
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_arbitration_criteria(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM arbitration_criteria
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
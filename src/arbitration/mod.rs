@@ -0,0 +1,374 @@
+pub mod db;
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use arbitration_data::model::mapped::ArbitrationInfo;
+use poise::ChoiceParameter;
+use serde::{Deserialize, Serialize};
+
+use crate::commands::arbi::UserArbitrationTier;
+
+/// A star chart node as described by `regions.json`, with its names resolved in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub id: String,
+    pub name: String,
+    pub planet: String,
+    pub mission_type: String,
+    pub faction: String,
+    pub min_enemy_level: u32,
+    pub max_enemy_level: u32,
+    pub mastery_req: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawNode {
+    name: String,
+    system_name: String,
+    mission_name: String,
+    faction_name: String,
+    min_enemy_level: u32,
+    max_enemy_level: u32,
+    mastery_req: u32,
+}
+
+/// The nodes of `regions.json` with their names still unresolved, so the export is only parsed
+/// once for all languages.
+#[derive(Debug, Deserialize)]
+#[serde(transparent)]
+pub struct RegionsExport(HashMap<String, RawNode>);
+
+/// The nodes of `regions.json` in a single language.
+#[derive(Debug, Clone)]
+pub struct Regions {
+    nodes: HashMap<String, Node>,
+    /// How often each node appears in the arbitration rotation, by node id.
+    arbitration_counts: BTreeMap<String, usize>,
+    /// The number of entries in the arbitration rotation.
+    schedule_len: usize,
+}
+
+impl Regions {
+    /// Resolves `export` through `dict`, keeping the raw language key where a name is missing.
+    ///
+    /// `arbitration_schedule` is the `timestamp,SolNode` CSV the arbitration data is built from.
+    pub fn new(
+        export: &RegionsExport,
+        dict: &HashMap<String, String>,
+        arbitration_schedule: &str,
+    ) -> Self {
+        let lookup = |key: &str| dict.get(key).cloned().unwrap_or_else(|| key.to_owned());
+
+        let nodes = export
+            .0
+            .iter()
+            .map(|(id, node)| {
+                let node = Node {
+                    id: id.clone(),
+                    name: lookup(&node.name),
+                    planet: lookup(&node.system_name),
+                    mission_type: lookup(&node.mission_name),
+                    faction: lookup(&node.faction_name),
+                    min_enemy_level: node.min_enemy_level,
+                    max_enemy_level: node.max_enemy_level,
+                    mastery_req: node.mastery_req,
+                };
+                (id.clone(), node)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut arbitration_counts = BTreeMap::new();
+        let mut schedule_len = 0;
+
+        for (_, node) in arbitration_schedule
+            .lines()
+            .filter_map(|line| line.split_once(','))
+        {
+            let node = node.trim();
+
+            if nodes.contains_key(node) {
+                *arbitration_counts.entry(node.to_owned()).or_default() += 1;
+                schedule_len += 1;
+            }
+        }
+
+        Self {
+            nodes,
+            arbitration_counts,
+            schedule_len,
+        }
+    }
+
+    pub fn node(&self, id: &str) -> Option<&Node> {
+        self.nodes.get(id)
+    }
+
+    /// Every node of the star chart, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &Node> {
+        self.nodes.values()
+    }
+
+    /// Finds a node by its id or its name in this language.
+    pub fn find_node(&self, query: &str) -> Option<&Node> {
+        self.node(query)
+            .or_else(|| self.nodes().find(|node| eq_ignore_case(&node.name, query)))
+    }
+
+    /// All nodes that can host an arbitration.
+    pub fn arbitration_nodes(&self) -> impl Iterator<Item = &Node> {
+        self.arbitration_counts
+            .keys()
+            .filter_map(|id| self.nodes.get(id))
+    }
+
+    /// How often the node `id` appears in the arbitration rotation.
+    pub fn arbitration_count(&self, id: &str) -> usize {
+        self.arbitration_counts.get(id).copied().unwrap_or_default()
+    }
+
+    /// The number of entries in the arbitration rotation.
+    pub fn schedule_len(&self) -> usize {
+        self.schedule_len
+    }
+
+    /// The distinct values of `field` across all arbitration nodes, sorted.
+    pub fn arbitration_values(&self, field: FilterField) -> BTreeSet<&str> {
+        self.arbitration_nodes()
+            .map(|node| field.of_node(node))
+            .collect()
+    }
+
+    /// Maps a (possibly localized) value of `field` to its name in `english`.
+    ///
+    /// Both the names in this language and the English names are accepted.
+    pub fn canonicalize(
+        &self,
+        english: &Regions,
+        field: FilterField,
+        value: &str,
+    ) -> Option<String> {
+        self.arbitration_nodes()
+            .chain(english.arbitration_nodes())
+            .find(|node| eq_ignore_case(field.of_node(node), value))
+            .and_then(|node| english.node(&node.id))
+            .map(|node| field.of_node(node).to_owned())
+    }
+}
+
+fn eq_ignore_case(a: &str, b: &str) -> bool {
+    a.to_lowercase() == b.to_lowercase()
+}
+
+/// A property of an arbitration that can be filtered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ChoiceParameter)]
+pub enum FilterField {
+    #[name = "Mission Type"]
+    #[name_localized("de", "Missionstyp")]
+    #[name_localized("fr", "Type de mission")]
+    MissionType,
+
+    #[name = "Faction"]
+    #[name_localized("de", "Fraktion")]
+    #[name_localized("fr", "Faction")]
+    Faction,
+
+    #[name = "Planet"]
+    #[name_localized("de", "Planet")]
+    #[name_localized("fr", "Planète")]
+    Planet,
+
+    #[name = "Node"]
+    #[name_localized("de", "Knoten")]
+    #[name_localized("fr", "Nœud")]
+    Node,
+}
+
+impl FilterField {
+    pub fn of_node(self, node: &Node) -> &str {
+        match self {
+            FilterField::MissionType => &node.mission_type,
+            FilterField::Faction => &node.faction,
+            FilterField::Planet => &node.planet,
+            FilterField::Node => &node.name,
+        }
+    }
+
+    pub fn of_arbitration(self, arbi: &ArbitrationInfo) -> &str {
+        match self {
+            FilterField::MissionType => &arbi.mission_type,
+            FilterField::Faction => &arbi.faction,
+            FilterField::Planet => &arbi.planet,
+            FilterField::Node => &arbi.node,
+        }
+    }
+}
+
+/// Optional constraints on an arbitration. Values are stored with their English names.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ArbitrationFilter {
+    pub mission_type: Option<String>,
+    pub faction: Option<String>,
+    pub planet: Option<String>,
+    pub node: Option<String>,
+}
+
+impl ArbitrationFilter {
+    fn fields(&self) -> [(FilterField, Option<&String>); 4] {
+        [
+            (FilterField::MissionType, self.mission_type.as_ref()),
+            (FilterField::Faction, self.faction.as_ref()),
+            (FilterField::Planet, self.planet.as_ref()),
+            (FilterField::Node, self.node.as_ref()),
+        ]
+    }
+
+    fn fields_mut(&mut self) -> [(FilterField, &mut Option<String>); 4] {
+        [
+            (FilterField::MissionType, &mut self.mission_type),
+            (FilterField::Faction, &mut self.faction),
+            (FilterField::Planet, &mut self.planet),
+            (FilterField::Node, &mut self.node),
+        ]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields().iter().all(|(_, value)| value.is_none())
+    }
+
+    /// Whether `arbi`, taken from the English arbitration data, passes every set constraint.
+    pub fn matches(&self, arbi: &ArbitrationInfo) -> bool {
+        self.matches_values(|field| field.of_arbitration(arbi))
+    }
+
+    /// Whether the English values returned by `value_of` pass every set constraint.
+    fn matches_values<'a>(&self, value_of: impl Fn(FilterField) -> &'a str) -> bool {
+        self.fields()
+            .iter()
+            .all(|(field, value)| value.is_none_or(|value| eq_ignore_case(value_of(*field), value)))
+    }
+
+    /// Replaces every set value with its English name.
+    ///
+    /// On failure, returns the field and the value that is not a known name.
+    pub fn canonicalize(
+        mut self,
+        regions: &Regions,
+        english: &Regions,
+    ) -> Result<Self, (FilterField, String)> {
+        for (field, value) in self.fields_mut() {
+            if let Some(input) = value.take() {
+                match regions.canonicalize(english, field, &input) {
+                    Some(canonical) => *value = Some(canonical),
+                    None => return Err((field, input)),
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// The set constraints as `(field, value)` pairs, for displaying them.
+    pub fn describe(&self) -> impl Iterator<Item = (FilterField, &str)> {
+        self.fields()
+            .into_iter()
+            .filter_map(|(field, value)| value.map(|value| (field, value.as_str())))
+    }
+}
+
+/// What a guild's arbitration subscription is notified about.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArbitrationCriteria {
+    /// `None` means S-Tier only.
+    pub minimum_tier: Option<UserArbitrationTier>,
+    pub filter: ArbitrationFilter,
+}
+
+impl ArbitrationCriteria {
+    pub fn minimum_tier(&self) -> UserArbitrationTier {
+        self.minimum_tier.clone().unwrap_or(UserArbitrationTier::S)
+    }
+
+    /// Whether `arbi`, taken from the English arbitration data, should be notified about.
+    pub fn matches(&self, arbi: &ArbitrationInfo) -> bool {
+        self.minimum_tier().includes(&arbi.tier) && self.filter.matches(arbi)
+    }
+}
+
+#[test]
+fn filters_are_canonicalized_and_matched() {
+    let export: RegionsExport = serde_json::from_str(
+        r#"{
+        "SolNode1": {
+            "name": "/Node/Galatea",
+            "systemName": "/System/Neptune",
+            "missionName": "/Mission/Capture",
+            "factionName": "/Faction/Corpus",
+            "minEnemyLevel": 27,
+            "maxEnemyLevel": 37,
+            "masteryReq": 0
+        }
+    }"#,
+    )
+    .unwrap();
+    let dict = |entries: [(&str, &str); 4]| {
+        entries
+            .into_iter()
+            .map(|(key, name)| (key.to_owned(), name.to_owned()))
+            .collect::<HashMap<_, _>>()
+    };
+    let schedule = "1760000000,SolNode1";
+
+    let english = Regions::new(
+        &export,
+        &dict([
+            ("/Node/Galatea", "Galatea"),
+            ("/System/Neptune", "Neptune"),
+            ("/Mission/Capture", "Capture"),
+            ("/Faction/Corpus", "Corpus"),
+        ]),
+        schedule,
+    );
+    let german = Regions::new(
+        &export,
+        &dict([
+            ("/Node/Galatea", "Galatea"),
+            ("/System/Neptune", "Neptun"),
+            ("/Mission/Capture", "Gefangennahme"),
+            ("/Faction/Corpus", "Corpus"),
+        ]),
+        schedule,
+    );
+
+    // localized and English names, in any case, are stored in English
+    let filter = ArbitrationFilter {
+        mission_type: Some("gefangennahme".to_owned()),
+        planet: Some("NEPTUNE".to_owned()),
+        ..Default::default()
+    }
+    .canonicalize(&german, &english)
+    .unwrap();
+
+    assert_eq!(filter.mission_type.as_deref(), Some("Capture"));
+    assert_eq!(filter.planet.as_deref(), Some("Neptune"));
+
+    let unknown = ArbitrationFilter {
+        faction: Some("Grineer".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(
+        unknown.canonicalize(&german, &english),
+        Err((FilterField::Faction, "Grineer".to_owned()))
+    );
+
+    let galatea = english.node("SolNode1").unwrap();
+    assert!(filter.matches_values(|field| field.of_node(galatea)));
+    assert!(ArbitrationFilter::default().matches_values(|field| field.of_node(galatea)));
+    assert!(
+        !ArbitrationFilter {
+            node: Some("Casta".to_owned()),
+            ..Default::default()
+        }
+        .matches_values(|field| field.of_node(galatea))
+    );
+}
@@ -0,0 +1,86 @@
+use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{TimedEvent, queryable::Alert};
+
+use crate::{
+    CmdRet,
+    Context,
+    commands::event::format_reward,
+    i18n::t,
+    settings::Preferences,
+    utils::{ApplyIf, ContextExt},
+};
+
+/// Discord allows at most 10 embeds per message.
+const MAX_ALERTS: usize = 10;
+
+pub fn alert_embed(prefs: &Preferences, alert: &Alert) -> CreateEmbed {
+    let lang = prefs.language;
+    let mission = &alert.mission;
+
+    let title = match &alert.tag {
+        Some(tag) => format!("{} ({tag})", mission.node),
+        None => mission.node.clone(),
+    };
+
+    prefs
+        .embed()
+        .title(title)
+        .field(t!(lang, "arbi.mission_type"), &mission.mission_type, true)
+        .field(t!(lang, "node.faction"), &mission.faction, true)
+        .field(
+            t!(lang, "node.enemy_levels"),
+            format!("{} - {}", mission.min_enemy_level, mission.max_enemy_level),
+            true,
+        )
+        .field(
+            t!(lang, "alerts.reward"),
+            format_reward(prefs, &mission.reward),
+            false,
+        )
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(alert.expiry()),
+            false,
+        )
+}
+
+/// Shows the active alerts, including special and tactical alerts
+#[command(slash_command)]
+pub async fn alerts(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let alerts = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<Alert>(lang.into())
+        .await?;
+
+    if alerts.is_empty() {
+        ctx.send(
+            CreateReply::default()
+                .content(t!(lang, "alerts.none"))
+                .ephemeral(prefs.ephemeral),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    // the alerts that don't fit are at least counted, so none go missing unnoticed
+    let hidden = alerts.len().saturating_sub(MAX_ALERTS);
+
+    ctx.send(
+        alerts
+            .iter()
+            .take(MAX_ALERTS)
+            .fold(CreateReply::default(), |reply, alert| {
+                reply.embed(alert_embed(&prefs, alert))
+            })
+            .apply_if(hidden > 0, |reply| {
+                reply.content(t!(lang, "alerts.more", count = hidden))
+            })
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
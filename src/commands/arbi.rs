@@ -1,29 +1,64 @@
-use std::time::Duration;
+use std::collections::{BTreeMap, HashMap};
 
-use arbitration_data::model::mapped::Tier;
+use arbitration_data::model::mapped::{ArbitrationInfo, Tier};
+use chrono::DateTime;
 use poise::{
     ChoiceParameter,
     CreateReply,
     command,
-    serenity_prelude::{CreateEmbed, FormattedTimestamp, FormattedTimestampStyle, Timestamp},
+    serenity_prelude::{AutocompleteChoice, ButtonStyle, CreateActionRow},
 };
-use poise_paginator::{CancellationType, paginate};
+use serde::{Deserialize, Serialize};
 
-use crate::{CmdRet, Context, Error, utils::embed};
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    arbitration::{ArbitrationFilter, FilterField},
+    components::{ComponentState, Page, open_button, send_pages},
+    game_data::GameData,
+    i18n::{Language, LocalizedChoice, t},
+    settings::Preferences,
+    utils::ContextExt,
+};
 
-#[derive(ChoiceParameter, derive_more::Display, Clone, Debug)]
+#[derive(
+    ChoiceParameter,
+    derive_more::Display,
+    Clone,
+    Debug,
+    PartialEq,
+    Eq,
+    sqlx::Type,
+    Serialize,
+    Deserialize,
+)]
+#[sqlx(rename_all = "lowercase")]
 pub enum UserArbitrationTier {
     #[name = "S Tier Map"]
+    #[name_localized("de", "S-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang S")]
     S,
     #[name = "A Tier Map"]
+    #[name_localized("de", "A-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang A")]
     A,
     #[name = "B Tier Map"]
+    #[name_localized("de", "B-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang B")]
     B,
     #[name = "C Tier Map"]
+    #[name_localized("de", "C-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang C")]
     C,
     #[name = "D Tier Map"]
+    #[name_localized("de", "D-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang D")]
     D,
     #[name = "F Tier Map"]
+    #[name_localized("de", "F-Tier-Karte")]
+    #[name_localized("fr", "Carte de rang F")]
     F,
 }
 
@@ -40,6 +75,115 @@ impl From<UserArbitrationTier> for Tier {
     }
 }
 
+/// Ranks a [`Tier`] from best (`0`, S) to worst (`5`, F).
+fn tier_rank(tier: &Tier) -> u8 {
+    match tier {
+        Tier::S => 0,
+        Tier::A => 1,
+        Tier::B => 2,
+        Tier::C => 3,
+        Tier::D => 4,
+        Tier::F => 5,
+    }
+}
+
+impl UserArbitrationTier {
+    /// Whether `tier` is this tier or a better one.
+    pub fn includes(&self, tier: &Tier) -> bool {
+        tier_rank(tier) <= tier_rank(&self.clone().into())
+    }
+}
+
+/// Autocompletes a value of `field` from the arbitration nodes in `regions.json`.
+///
+/// Choices are shown in the user's language, but submit the English name.
+async fn autocomplete_field(
+    ctx: Context<'_>,
+    partial: &str,
+    field: FilterField,
+) -> Vec<AutocompleteChoice> {
+    let lang = ctx
+        .preferences()
+        .await
+        .map(|prefs| prefs.language)
+        .unwrap_or_default();
+    let game_data = ctx.data().game_data();
+    let regions = game_data.localized_regions(lang);
+    let english = game_data.regions();
+    let partial = partial.to_lowercase();
+
+    regions
+        .arbitration_nodes()
+        .filter_map(|node| {
+            let english_node = english.node(&node.id)?;
+            Some((field.of_node(node), field.of_node(english_node)))
+        })
+        .collect::<BTreeMap<_, _>>()
+        .into_iter()
+        .filter(|(name, _)| name.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|(name, value)| AutocompleteChoice::new(name, value))
+        .collect()
+}
+
+pub async fn autocomplete_mission_type(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    autocomplete_field(ctx, partial, FilterField::MissionType).await
+}
+
+pub async fn autocomplete_faction(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    autocomplete_field(ctx, partial, FilterField::Faction).await
+}
+
+pub async fn autocomplete_planet(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    autocomplete_field(ctx, partial, FilterField::Planet).await
+}
+
+pub async fn autocomplete_node(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    autocomplete_field(ctx, partial, FilterField::Node).await
+}
+
+/// Maps the filter values a user entered, possibly in their own language, to English names.
+pub fn resolve_filter(
+    ctx: Context<'_>,
+    lang: Language,
+    filter: ArbitrationFilter,
+) -> Result<ArbitrationFilter, Error> {
+    let game_data = ctx.data().game_data();
+
+    filter
+        .canonicalize(game_data.localized_regions(lang), game_data.regions())
+        .map_err(|(field, value)| {
+            t!(
+                lang,
+                "arbi.unknown_filter_value",
+                field = field.name_in(lang),
+                value = value
+            )
+            .into()
+        })
+}
+
+/// The upcoming arbitrations in `lang` that pass `filter`.
+///
+/// Filters are evaluated against the English data, as that is what their values refer to. The
+/// localized rows are looked up by their key, as each `iter_upcoming` skips the rows that expired
+/// by the time it is called.
+pub fn filtered_upcoming<'a>(
+    data: &'a GameData,
+    lang: Language,
+    filter: &'a ArbitrationFilter,
+) -> impl Iterator<Item = (&'a i64, &'a ArbitrationInfo)> + 'a {
+    let localized = data
+        .localized_arbi_data(lang)
+        .iter_upcoming()
+        .collect::<HashMap<_, _>>();
+
+    data.arbi_data()
+        .iter_upcoming()
+        .filter(move |(_, english)| filter.matches(english))
+        .filter_map(move |(key, _)| localized.get(key).map(|localized| (key, *localized)))
+}
+
 /// Shows you the upcoming Arbitration, optionally filtered by a Map Tier
 #[command(slash_command, rename = "upcoming-arbitration")]
 pub async fn upcoming_arbitration(
@@ -47,126 +191,207 @@ pub async fn upcoming_arbitration(
     #[description = "The Tier of the Arbitration Map you want to look up"] tier: Option<
         UserArbitrationTier,
     >,
+    #[description = "Only show this mission type"]
+    #[autocomplete = autocomplete_mission_type]
+    mission_type: Option<String>,
+    #[description = "Only show Arbitrations against this faction"]
+    #[autocomplete = autocomplete_faction]
+    faction: Option<String>,
+    #[description = "Only show Arbitrations on this planet"]
+    #[autocomplete = autocomplete_planet]
+    planet: Option<String>,
+    #[description = "Only show Arbitrations on this node"]
+    #[autocomplete = autocomplete_node]
+    node: Option<String>,
 ) -> CmdRet {
-    let mut embed = embed();
-    let arbi_info = if let Some(tier) = tier {
-        match ctx.data().arbi_data().upcoming_by_tier(tier.clone().into()) {
-            Ok(data) => data,
-            Err(_) => {
-                ctx.say(format!(
-                    "Could not find any upcoming {tier} Tier Arbitrations.",
-                ))
-                .await?;
-                return Ok(());
-            },
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let game_data = ctx.data().game_data();
+    let arbi_data = game_data.localized_arbi_data(lang);
+
+    if !game_data.has_upcoming_arbitrations() {
+        ctx.say(t!(lang, "arbi.schedule_exhausted")).await?;
+        return Ok(());
+    }
+
+    let filter = resolve_filter(
+        ctx,
+        lang,
+        ArbitrationFilter {
+            mission_type,
+            faction,
+            planet,
+            node,
+        },
+    )?;
+
+    let mut embed = prefs.embed();
+    let arbi_info = if filter.is_empty() {
+        match &tier {
+            Some(tier) => arbi_data.upcoming_by_tier(tier.clone().into()).ok(),
+            None => arbi_data.upcoming().ok(),
         }
     } else {
-        match ctx.data().arbi_data().upcoming() {
-            Ok(data) => data,
-            Err(_) => {
-                ctx.say("Could not find any upcoming Arbitrations.").await?;
-                return Ok(());
-            },
-        }
+        filtered_upcoming(&game_data, lang, &filter)
+            .map(|(_, arbi)| arbi)
+            .find(|arbi| {
+                tier.as_ref()
+                    .is_none_or(|tier| arbi.tier == tier.clone().into())
+            })
+    };
+
+    let Some(arbi_info) = arbi_info else {
+        let message = match tier {
+            _ if !filter.is_empty() => t!(lang, "arbi.none_matching").to_owned(),
+            Some(tier) => t!(lang, "arbi.none_upcoming_tier", tier = tier),
+            None => t!(lang, "arbi.none_upcoming").to_owned(),
+        };
+
+        ctx.say(message).await?;
+        return Ok(());
     };
 
     embed = embed
-        .title(format!("{} Tier Arbitration", arbi_info.tier))
-        .field(
-            "Node",
-            format!(
-                "[{}](https://youtu.be/8ybW48rKBME?si=3pbdudMX_CTUAJ8T)",
-                &arbi_info.node
-            ),
-            true,
-        )
+        .title(t!(lang, "arbi.title", tier = arbi_info.tier))
+        .field(t!(lang, "arbi.node"), &arbi_info.node, true)
+        .field(t!(lang, "arbi.planet"), &arbi_info.planet, true)
         .field(
-            "Planet",
-            format!(
-                "[{}](https://youtu.be/8ybW48rKBME?si=3pbdudMX_CTUAJ8T)",
-                &arbi_info.planet
-            ),
-            true,
-        )
-        .field(
-            "Mission Type",
-            format!(
-                "**{}**, against **{}**",
-                &arbi_info.mission_type, arbi_info.faction
+            t!(lang, "arbi.mission_type"),
+            t!(
+                lang,
+                "arbi.mission_against",
+                mission_type = arbi_info.mission_type,
+                faction = arbi_info.faction
             ),
             false,
         )
         .field(
-            "Starts",
-            FormattedTimestamp::new(
-                Timestamp::from_unix_timestamp(arbi_info.activation.timestamp())?,
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "common.starts"),
+            prefs.format_time(arbi_info.activation),
             true,
         )
         .field(
-            "Ends",
-            FormattedTimestamp::new(
-                Timestamp::from_unix_timestamp(arbi_info.expiry.timestamp())?,
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "common.ends"),
+            prefs.format_time(arbi_info.expiry),
             true,
         );
 
-    ctx.send(CreateReply::default().embed(embed)).await?;
+    let node_id = game_data
+        .localized_regions(lang)
+        .find_node(&arbi_info.node)
+        .map(|node| node.id.clone());
+
+    let Some(node_id) = node_id else {
+        ctx.send(
+            CreateReply::default()
+                .embed(embed)
+                .ephemeral(prefs.ephemeral),
+        )
+        .await?;
+        return Ok(());
+    };
+
+    let state_id = ComponentState::NodeDetails { node_id }
+        .save(ctx.data(), ctx.author().id.get() as i64)
+        .await?;
+
+    let node_button = open_button(state_id)
+        .label(t!(lang, "node.details"))
+        .style(ButtonStyle::Secondary);
+
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .components(vec![CreateActionRow::Buttons(vec![node_button])])
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
 
     Ok(())
 }
 
 const AMOUNT_PER_PAGE: usize = 10;
 
-async fn get_page(
-    ctx: Context<'_>,
-    idx: usize,
-    _cancellation_type: CancellationType,
+/// The query behind the pages of `/upcoming-arbitrations`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpcomingArbitrations {
     tier: Option<UserArbitrationTier>,
-) -> Result<CreateEmbed, Error> {
-    let arbi_data = &ctx.data().arbi_data;
-    let mut description = String::new();
-    description.push_str(&format!(
-        "**`Tier  {:<15} {:<10} When`**\n",
-        "Node", "Planet"
-    ));
-
-    let skip = AMOUNT_PER_PAGE * idx;
-    let take = AMOUNT_PER_PAGE;
-    for (key, value) in arbi_data
-        .iter_upcoming()
-        .filter(|(_k, v)| {
-            tier.as_ref()
+    filter: ArbitrationFilter,
+    group_by_day: bool,
+}
+
+impl UpcomingArbitrations {
+    fn iter<'a>(
+        &'a self,
+        data: &'a GameData,
+        lang: Language,
+    ) -> impl Iterator<Item = (&'a i64, &'a ArbitrationInfo)> {
+        filtered_upcoming(data, lang, &self.filter).filter(|(_k, v)| {
+            self.tier
+                .as_ref()
                 .map(|tier| v.tier == tier.clone().into())
                 .unwrap_or(true)
         })
-        .skip(skip)
-        .take(take)
-    {
-        description.push_str(&format!(
-            "`[{}]   {:<15} {:<10}` {}\n",
-            value.tier,
-            value.node,
-            value.planet,
-            FormattedTimestamp::new(
-                Timestamp::from_unix_timestamp(*key).unwrap(),
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-        ))
     }
 
-    if !description.is_empty() {
-        let title = match tier {
-            Some(tier) => format!("Upcoming {tier} Tier Arbitrations"),
-            None => "Upcoming Arbitrations".to_owned(),
+    /// The page at `index`, or the last one if fewer Arbitrations are left by now.
+    pub fn page(&self, data: &AppData, prefs: &Preferences, index: usize) -> Result<Page, Error> {
+        let lang = prefs.language;
+        let game_data = data.game_data();
+
+        let count = self
+            .iter(&game_data, lang)
+            .count()
+            .div_ceil(AMOUNT_PER_PAGE);
+        if count == 0 {
+            return Err(t!(lang, "arbi.none_found").into());
+        }
+
+        let index = index.min(count - 1);
+        let mut description = String::new();
+        description.push_str(&format!(
+            "**`{:<6}{:<15} {:<10} {}`**\n",
+            t!(lang, "arbi.tier"),
+            t!(lang, "arbi.node"),
+            t!(lang, "arbi.planet"),
+            t!(lang, "arbi.when"),
+        ));
+
+        let mut current_day = None;
+        let skip = AMOUNT_PER_PAGE * index;
+        let take = AMOUNT_PER_PAGE;
+        for (key, value) in self.iter(&game_data, lang).skip(skip).take(take) {
+            let Some(activation) = DateTime::from_timestamp(*key, 0) else {
+                continue;
+            };
+
+            if self.group_by_day {
+                let day = prefs.local_date(activation);
+                if current_day != Some(day) {
+                    current_day = Some(day);
+                    description.push_str(&format!("\n__{}__\n", prefs.day_heading(day)));
+                }
+            }
+
+            description.push_str(&format!(
+                "`[{}]   {:<15} {:<10}` {}\n",
+                value.tier,
+                value.node,
+                value.planet,
+                prefs.format_time(activation)
+            ))
+        }
+
+        let title = match &self.tier {
+            Some(tier) => t!(lang, "arbi.upcoming_tier_title", tier = tier),
+            None => t!(lang, "arbi.upcoming_title").to_owned(),
         };
-        Ok(embed().description(description).title(title))
-    } else {
-        Err("No arbitrations found".into())
+
+        Ok(Page::new(
+            prefs.embed().description(description).title(title),
+            index,
+            count,
+        ))
     }
 }
 
@@ -177,27 +402,52 @@ pub async fn upcoming_arbitrations(
     #[description = "The Tier of the Arbitration Map you want to look up"] tier: Option<
         UserArbitrationTier,
     >,
+    #[description = "Only show this mission type"]
+    #[autocomplete = autocomplete_mission_type]
+    mission_type: Option<String>,
+    #[description = "Only show Arbitrations against this faction"]
+    #[autocomplete = autocomplete_faction]
+    faction: Option<String>,
+    #[description = "Only show Arbitrations on this planet"]
+    #[autocomplete = autocomplete_planet]
+    planet: Option<String>,
+    #[description = "Only show Arbitrations on this node"]
+    #[autocomplete = autocomplete_node]
+    node: Option<String>,
+    #[description = "Whether to group the Arbitrations by day"] group_by_day: Option<bool>,
 ) -> CmdRet {
-    let paginator_length = ctx
-        .data()
-        .arbi_data
-        .iter_upcoming()
-        .filter(|(_k, v)| {
-            tier.as_ref()
-                .map(|tier| v.tier == tier.clone().into())
-                .unwrap_or(true)
-        })
-        .count()
-        / AMOUNT_PER_PAGE;
+    let prefs = ctx.preferences().await?;
+    let game_data = ctx.data().game_data();
+
+    if !game_data.has_upcoming_arbitrations() {
+        ctx.say(t!(prefs.language, "arbi.schedule_exhausted"))
+            .await?;
+        return Ok(());
+    }
 
-    paginate(
+    let filter = resolve_filter(
         ctx,
-        get_page,
-        paginator_length,
-        Duration::from_secs(180),
+        prefs.language,
+        ArbitrationFilter {
+            mission_type,
+            faction,
+            planet,
+            node,
+        },
+    )?;
+
+    let upcoming = UpcomingArbitrations {
         tier,
-    )
-    .await?;
+        filter,
+        group_by_day: group_by_day.unwrap_or(false),
+    };
+
+    if upcoming.iter(&game_data, prefs.language).next().is_none() {
+        ctx.say(t!(prefs.language, "arbi.none_matching")).await?;
+        return Ok(());
+    }
+
+    send_pages(ctx, &prefs, ComponentState::UpcomingArbitrations(upcoming)).await?;
 
     Ok(())
 }
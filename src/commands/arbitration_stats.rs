@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use arbitration_data::model::mapped::Tier;
+use chrono::{TimeDelta, Utc};
+use itertools::Itertools;
+use poise::{CreateReply, command};
+
+use crate::{CmdRet, Context, i18n::t, utils::ContextExt};
+
+const DEFAULT_DAYS: u32 = 28;
+
+/// The tiers from best to worst, which is the order their missions are listed in.
+const TIERS: [Tier; 6] = [Tier::S, Tier::A, Tier::B, Tier::C, Tier::D, Tier::F];
+
+/// Rates per week over a shorter window than this would be extrapolated from almost nothing.
+const MIN_RATE_WINDOW: TimeDelta = TimeDelta::days(1);
+
+/// Shows statistics about the upcoming Arbitration rotation
+#[command(slash_command, rename = "arbitration-stats")]
+pub async fn arbitration_stats(
+    ctx: Context<'_>,
+    #[description = "How many days ahead to look (default: 28)"]
+    #[min = 7]
+    #[max = 365]
+    days: Option<u32>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let game_data = ctx.data().game_data();
+
+    if !game_data.has_upcoming_arbitrations() {
+        ctx.say(t!(lang, "arbi.schedule_exhausted")).await?;
+        return Ok(());
+    }
+
+    let now = Utc::now();
+    let until = now + TimeDelta::days(days.unwrap_or(DEFAULT_DAYS).into());
+
+    let arbis = game_data
+        .localized_arbi_data(lang)
+        .iter_upcoming()
+        .map(|(_, arbi)| arbi)
+        .take_while(|arbi| arbi.activation < until)
+        .collect::<Vec<_>>();
+
+    // the schedule may end before the requested window does
+    let window_end = arbis.last().map_or(until, |arbi| arbi.expiry.min(until));
+    let window = window_end - now;
+
+    let s_tiers = arbis
+        .iter()
+        .filter(|arbi| matches!(arbi.tier, Tier::S))
+        .collect::<Vec<_>>();
+    let a_tiers = arbis
+        .iter()
+        .filter(|arbi| matches!(arbi.tier, Tier::A))
+        .count();
+
+    let per_week = if window < MIN_RATE_WINDOW {
+        "-".to_owned()
+    } else {
+        let weeks = window.num_seconds() as f64 / TimeDelta::weeks(1).num_seconds() as f64;
+
+        t!(
+            lang,
+            "arbi_stats.per_week_value",
+            s = format!("{:.1}", s_tiers.len() as f64 / weeks),
+            a = format!("{:.1}", a_tiers as f64 / weeks)
+        )
+    };
+
+    let average_gap = s_tiers
+        .iter()
+        .tuple_windows()
+        .map(|(previous, next)| next.activation - previous.activation)
+        .reduce(|sum, gap| sum + gap)
+        .map(|sum| prefs.format_duration(sum / (s_tiers.len() as i32 - 1)))
+        .unwrap_or_else(|| "-".to_owned());
+
+    // a drought is a stretch without an S-Tier, starting now
+    let drought = std::iter::once(now)
+        .chain(s_tiers.iter().map(|arbi| arbi.expiry))
+        .zip(
+            s_tiers
+                .iter()
+                .map(|arbi| arbi.activation)
+                .chain(std::iter::once(window_end)),
+        )
+        .max_by_key(|(from, to)| *to - *from)
+        .map(|(from, to)| {
+            t!(
+                lang,
+                "arbi_stats.drought_value",
+                duration = prefs.format_duration(to - from),
+                from = prefs.format_time(from),
+                to = prefs.format_time(to)
+            )
+        })
+        .unwrap_or_else(|| "-".to_owned());
+
+    let mut embed = prefs
+        .embed()
+        .title(t!(lang, "arbi_stats.title"))
+        .description(t!(
+            lang,
+            "arbi_stats.description",
+            count = arbis.len(),
+            until = prefs.format_time(window_end)
+        ))
+        .field(t!(lang, "arbi_stats.per_week"), per_week, false)
+        .field(t!(lang, "arbi_stats.average_gap"), average_gap, true)
+        .field(t!(lang, "arbi_stats.drought"), drought, false);
+
+    for tier in &TIERS {
+        let mut missions = HashMap::<&str, usize>::new();
+        for arbi in arbis.iter().filter(|arbi| arbi.tier == *tier) {
+            *missions.entry(arbi.mission_type.as_str()).or_default() += 1;
+        }
+
+        if missions.is_empty() {
+            continue;
+        }
+
+        let distribution = missions
+            .into_iter()
+            .sorted_by(|(a_name, a_count), (b_name, b_count)| {
+                b_count.cmp(a_count).then(a_name.cmp(b_name))
+            })
+            .map(|(mission_type, count)| format!("{mission_type} ×{count}"))
+            .join(", ");
+
+        embed = embed.field(
+            t!(lang, "arbi_stats.missions", tier = tier),
+            distribution,
+            false,
+        );
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -1,88 +1,110 @@
 use chrono::{DateTime, Utc};
 use indoc::formatdoc;
 use itertools::Itertools;
-use poise::{
-    CreateReply,
-    command,
-    serenity_prelude::{CreateEmbed, FormattedTimestampStyle},
-};
+use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
 use warframe::worldstate::{ArchonHuntMission, TimedEvent, queryable::ArchonHunt};
 
-use crate::{
-    CmdRet,
-    Context,
-    utils::{embed, to_timestamp},
-};
+use crate::{CmdRet, Context, emojis, i18n::t, settings::Preferences, utils::ContextExt};
 
-#[derive(strum::Display)]
 enum ArchonShard {
-    #[strum(to_string = "<:shard_amber:1299400892357017610> **Yellow Archon Shard**")]
     Yellow,
-    #[strum(to_string = "<:shard_crimson:1299400724052054098> **Red Archon Shard**")]
     Red,
-    #[strum(to_string = "<:shard_azure:1299400931825418271> **Blue Archon Shard**")]
     Blue,
 }
 
 impl ArchonShard {
+    /// Archon names are the same in every language, so matching on the name is enough.
     fn from_boss_str(boss: &str) -> Self {
-        match boss {
-            "Archon Amar" => Self::Red,
-            "Archon Nira" => Self::Yellow,
-            "Archon Boreal" => Self::Blue,
-            _ => unreachable!("there are only 3 archons, which are covered here"),
+        if boss.contains("Amar") {
+            Self::Red
+        } else if boss.contains("Nira") {
+            Self::Yellow
+        } else if boss.contains("Boreal") {
+            Self::Blue
+        } else {
+            unreachable!("there are only 3 archons, which are covered here")
+        }
+    }
+
+    fn label(&self, prefs: &Preferences) -> String {
+        let lang = prefs.language;
+        let (emoji, name) = match self {
+            ArchonShard::Yellow => (emojis::SHARD_AMBER, t!(lang, "archon.shard.yellow")),
+            ArchonShard::Red => (emojis::SHARD_CRIMSON, t!(lang, "archon.shard.red")),
+            ArchonShard::Blue => (emojis::SHARD_AZURE, t!(lang, "archon.shard.blue")),
+        };
+
+        if prefs.custom_emojis {
+            format!("{emoji} **{name}**")
+        } else {
+            format!("**{name}**")
         }
     }
 }
 
 #[command(slash_command, rename = "archon-hunt")]
 pub async fn archon_hunt(ctx: Context<'_>) -> CmdRet {
-    let archon_hunt = ctx.data().worldstate().fetch::<ArchonHunt>().await?;
+    let prefs = ctx.preferences().await?;
+    let archon_hunt = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<ArchonHunt>(prefs.language.into())
+        .await?;
     let missions = &archon_hunt.missions;
     let obtainable_shard = ArchonShard::from_boss_str(&archon_hunt.boss);
 
     let embed = create_archon_hunt_embed(
+        &prefs,
         obtainable_shard,
         &archon_hunt.boss,
         archon_hunt.expiry(),
         missions,
     );
 
-    ctx.send(CreateReply::default().embed(embed)).await?;
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
 
     Ok(())
 }
 
 fn create_archon_hunt_embed(
+    prefs: &Preferences,
     shard: ArchonShard,
     boss: &str,
     expiry: DateTime<Utc>,
     missions: &[ArchonHuntMission],
 ) -> CreateEmbed {
-    embed()
+    let lang = prefs.language;
+
+    prefs
+        .embed()
         .title(boss)
         .url(format!("https://warframe.fandom.com/wiki/{}", boss.split(' ').join("_")))
         .description(formatdoc!(
             "
-            {shard} obtainable
+            {}
 
-            __**{}** on **{}**__
-            Level: 130-135
+            __{}__
+            {level}: 130-135
 
-            __**{}** on **{}**__
-            Level: 135-140
+            __{}__
+            {level}: 135-140
 
-            __**{}** on **{}**__
-            Level: 145-150
+            __{}__
+            {level}: 145-150
 
-            Ends {}",
-            missions[0].type_key,
-            missions[0].node_key,
-            missions[1].type_key,
-            missions[1].node_key,
-            missions[2].type_key,
-            missions[2].node_key,
-            to_timestamp(expiry, FormattedTimestampStyle::RelativeTime).expect("timestamp should be correct")
+            {} {}",
+            t!(lang, "archon.obtainable", shard = shard.label(prefs)),
+            t!(lang, "archon.mission", mission_type = missions[0].type_key, node = missions[0].node_key),
+            t!(lang, "archon.mission", mission_type = missions[1].type_key, node = missions[1].node_key),
+            t!(lang, "archon.mission", mission_type = missions[2].type_key, node = missions[2].node_key),
+            t!(lang, "common.ends"),
+            prefs.format_time(expiry),
+            level = t!(lang, "archon.level"),
         ))
         .thumbnail(match shard {
             ArchonShard::Yellow => "https://static.wikia.nocookie.net/warframe/images/4/4c/ArchonNira.png/revision/latest?cb=20220418152944",
@@ -0,0 +1,267 @@
+use itertools::Itertools;
+use poise::{
+    CreateReply,
+    command,
+    serenity_prelude::{AutocompleteChoice, CreateEmbed},
+};
+use warframe::worldstate::{SyndicateMission, TimedEvent};
+
+use crate::{
+    CmdRet,
+    Context,
+    i18n::{Language, t},
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// Rewards worth pointing out in the overview, matched against the English and most
+/// localized item names.
+const NOTABLE_REWARDS: [&str; 7] = [
+    "Arcane", "Riven", "Forma", "Exilus", "Catalyst", "Reactor", "Adapter",
+];
+
+/// How many rewards are shown per bounty before the list is cut off.
+const MAX_REWARDS_PER_JOB: usize = 3;
+
+/// Discord's limit on the length of a field value.
+const FIELD_VALUE_LIMIT: usize = 1024;
+
+/// Discord's limit on the combined length of an embed's texts.
+const EMBED_LIMIT: usize = 6000;
+
+fn contains_ignore_case(text: &str, query: &str) -> bool {
+    text.to_lowercase().contains(&query.to_lowercase())
+}
+
+/// The rewards worth showing for a bounty: the ones matching `search`, or the notable ones.
+fn shown_rewards<'a>(reward_pool: &'a [String], search: Option<&str>) -> Vec<&'a str> {
+    reward_pool
+        .iter()
+        .map(String::as_str)
+        .filter(|reward| match search {
+            Some(search) => contains_ignore_case(reward, search),
+            None => NOTABLE_REWARDS
+                .iter()
+                .any(|notable| contains_ignore_case(reward, notable)),
+        })
+        .collect()
+}
+
+/// Every reward currently offered by any bounty in `lang` that contains `partial`.
+async fn reward_choices(
+    ctx: Context<'_>,
+    lang: Language,
+    partial: &str,
+) -> Vec<AutocompleteChoice> {
+    let Ok(missions) = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<SyndicateMission>(lang.into())
+        .await
+    else {
+        return Vec::new();
+    };
+
+    missions
+        .iter()
+        .flat_map(|mission| &mission.jobs)
+        .flat_map(|job| &job.reward_pool)
+        .filter(|reward| contains_ignore_case(reward, partial))
+        .sorted()
+        .dedup()
+        .take(25)
+        .map(|reward| AutocompleteChoice::new(reward, reward.as_str()))
+        .collect()
+}
+
+/// Autocompletes every reward currently offered by any bounty.
+async fn autocomplete_reward(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let lang = ctx
+        .preferences()
+        .await
+        .map(|prefs| prefs.language)
+        .unwrap_or_default();
+
+    reward_choices(ctx, lang, partial).await
+}
+
+/// Autocompletes the English names of every reward currently offered by any bounty, which is
+/// what the bounty notifier compares its filters against.
+pub async fn autocomplete_english_reward(
+    ctx: Context<'_>,
+    partial: &str,
+) -> Vec<AutocompleteChoice> {
+    reward_choices(ctx, Language::En, partial).await
+}
+
+/// One line per bounty tier of `mission`, or `None` if no tier matches `search`.
+fn syndicate_table(mission: &SyndicateMission, search: Option<&str>) -> Option<String> {
+    let lines = mission
+        .jobs
+        .iter()
+        .filter_map(|job| {
+            let rewards = shown_rewards(&job.reward_pool, search);
+
+            if search.is_some() && rewards.is_empty() {
+                return None;
+            }
+
+            let mut rewards_text = rewards.iter().take(MAX_REWARDS_PER_JOB).join(", ");
+            if rewards.len() > MAX_REWARDS_PER_JOB {
+                rewards_text.push_str(&format!(", +{}", rewards.len() - MAX_REWARDS_PER_JOB));
+            } else if rewards_text.is_empty() {
+                rewards_text.push('-');
+            }
+
+            Some(format!(
+                "`{:>7} {:>6}` {}",
+                job.enemy_levels.iter().join("-"),
+                job.standing_stages.iter().sum::<i32>(),
+                rewards_text
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Shortens `text` to at most `limit` characters, dropping whole lines where possible and
+/// marking the cut with `…`.
+fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_owned();
+    }
+
+    let mut kept = String::new();
+    let mut length = 0;
+
+    for line in text.lines() {
+        // the line, its line break and the ellipsis after it
+        let line_length = line.chars().count() + 1;
+        if length + line_length + 1 > limit {
+            break;
+        }
+
+        kept.push_str(line);
+        kept.push('\n');
+        length += line_length;
+    }
+
+    if kept.is_empty() {
+        kept = text.chars().take(limit - 1).collect();
+    }
+
+    kept.push('…');
+    kept
+}
+
+/// The overview of every syndicate's bounties, cut to fit Discord's limits, as long localized
+/// reward names can exceed them.
+fn create_bounties_embed(
+    prefs: &Preferences,
+    missions: &[SyndicateMission],
+    search: Option<&str>,
+) -> Option<CreateEmbed> {
+    let lang = prefs.language;
+
+    let title = match search {
+        Some(search) => t!(lang, "bounties.search_title", reward = search),
+        None => t!(lang, "bounties.title").to_owned(),
+    };
+    let description = t!(lang, "bounties.legend");
+
+    let mut length = title.chars().count() + description.chars().count();
+    let mut fields = Vec::new();
+
+    for mission in missions {
+        let Some(table) = syndicate_table(mission, search) else {
+            continue;
+        };
+
+        let ends = format!(
+            "{} {}",
+            t!(lang, "common.ends"),
+            prefs.format_time(mission.expiry())
+        );
+        let value = format!(
+            "{}\n{ends}",
+            truncate(&table, FIELD_VALUE_LIMIT - ends.chars().count() - 1)
+        );
+
+        let field_length = mission.syndicate.chars().count() + value.chars().count();
+        if length + field_length > EMBED_LIMIT {
+            break;
+        }
+
+        length += field_length;
+        fields.push((mission.syndicate.clone(), value, false));
+    }
+
+    if fields.is_empty() {
+        return None;
+    }
+
+    Some(
+        prefs
+            .embed()
+            .title(title)
+            .description(description)
+            .fields(fields),
+    )
+}
+
+/// Compares the bounties of all open-world syndicates, optionally searching for a reward
+#[command(slash_command)]
+pub async fn bounties(
+    ctx: Context<'_>,
+    #[description = "Only show bounties that can drop this reward"]
+    #[autocomplete = autocomplete_reward]
+    reward: Option<String>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+
+    // syndicates like Steel Meridian only have missions, but no bounties
+    let missions = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<SyndicateMission>(lang.into())
+        .await?
+        .into_iter()
+        .filter(|mission| !mission.jobs.is_empty())
+        .collect::<Vec<_>>();
+
+    let embed = create_bounties_embed(&prefs, &missions, reward.as_deref()).ok_or_else(|| {
+        reward.as_ref().map_or_else(
+            || t!(lang, "worldstate.bounties_not_found").to_owned(),
+            |reward| t!(lang, "bounties.not_found", reward = reward),
+        )
+    })?;
+
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[test]
+fn long_fields_are_truncated() {
+    let text = ["a".repeat(10), "b".repeat(10), "c".repeat(10)].join("\n");
+
+    assert_eq!(truncate(&text, 100), text);
+    assert_eq!(
+        truncate(&text, 23),
+        format!("{}\n{}\n…", "a".repeat(10), "b".repeat(10))
+    );
+    assert_eq!(truncate(&text, 5), "aaaa…");
+    assert!(
+        truncate(&text.repeat(200), FIELD_VALUE_LIMIT)
+            .chars()
+            .count()
+            <= FIELD_VALUE_LIMIT
+    );
+}
@@ -0,0 +1,171 @@
+use chrono::{TimeDelta, Utc};
+use poise::{CreateReply, command, serenity_prelude::CreateAttachment};
+use warframe::worldstate::queryable::Cetus;
+
+use crate::{
+    CmdRet,
+    Context,
+    arbitration::ArbitrationFilter,
+    commands::{
+        arbi::{
+            UserArbitrationTier,
+            autocomplete_faction,
+            autocomplete_mission_type,
+            autocomplete_node,
+            autocomplete_planet,
+            filtered_upcoming,
+            resolve_filter,
+        },
+        eidolons::{CETUS_NIGHT, night_starts},
+    },
+    i18n::t,
+    ical::{Calendar, Event},
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+const DEFAULT_DAYS: u32 = 14;
+
+#[command(
+    slash_command,
+    subcommands("arbitrations", "cetus_nights"),
+    subcommand_required
+)]
+pub async fn calendar(_: Context<'_>) -> CmdRet {
+    Ok(())
+}
+
+/// Exports upcoming Arbitrations as a calendar file (.ics)
+#[command(slash_command)]
+pub async fn arbitrations(
+    ctx: Context<'_>,
+    #[description = "Only include Arbitrations of this Tier or better"] minimum_tier: Option<
+        UserArbitrationTier,
+    >,
+    #[description = "Only include this mission type"]
+    #[autocomplete = autocomplete_mission_type]
+    mission_type: Option<String>,
+    #[description = "Only include Arbitrations against this faction"]
+    #[autocomplete = autocomplete_faction]
+    faction: Option<String>,
+    #[description = "Only include Arbitrations on this planet"]
+    #[autocomplete = autocomplete_planet]
+    planet: Option<String>,
+    #[description = "Only include Arbitrations on this node"]
+    #[autocomplete = autocomplete_node]
+    node: Option<String>,
+    #[description = "How many days to include (default: 14)"]
+    #[min = 1]
+    #[max = 90]
+    days: Option<u32>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let until = Utc::now() + TimeDelta::days(days.unwrap_or(DEFAULT_DAYS).into());
+
+    let filter = resolve_filter(
+        ctx,
+        lang,
+        ArbitrationFilter {
+            mission_type,
+            faction,
+            planet,
+            node,
+        },
+    )?;
+
+    let mut calendar = Calendar::new(t!(lang, "calendar.arbitrations.name"));
+
+    let game_data = ctx.data().game_data();
+
+    if !game_data.has_upcoming_arbitrations() {
+        ctx.say(t!(lang, "arbi.schedule_exhausted")).await?;
+        return Ok(());
+    }
+
+    for (key, arbi) in filtered_upcoming(&game_data, lang, &filter)
+        .take_while(|(_, arbi)| arbi.activation < until)
+        .filter(|(_, arbi)| {
+            minimum_tier
+                .as_ref()
+                .is_none_or(|tier| tier.includes(&arbi.tier))
+        })
+    {
+        calendar.push(Event {
+            uid: format!("arbitration-{key}@gaia"),
+            summary: t!(
+                lang,
+                "calendar.arbitrations.summary",
+                tier = arbi.tier,
+                mission_type = arbi.mission_type,
+                node = arbi.node
+            ),
+            description: Some(t!(
+                lang,
+                "arbi.mission_against",
+                mission_type = arbi.mission_type,
+                faction = arbi.faction
+            )),
+            location: Some(format!("{} ({})", arbi.node, arbi.planet)),
+            start: arbi.activation,
+            end: arbi.expiry,
+        });
+    }
+
+    send_calendar(ctx, prefs, calendar, "arbitrations.ics").await
+}
+
+/// Exports upcoming Cetus nights as a calendar file (.ics)
+#[command(slash_command, rename = "cetus-nights")]
+pub async fn cetus_nights(
+    ctx: Context<'_>,
+    #[description = "How many days to include (default: 14)"]
+    #[min = 1]
+    #[max = 90]
+    days: Option<u32>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let until = Utc::now() + TimeDelta::days(days.unwrap_or(DEFAULT_DAYS).into());
+
+    let cetus = ctx.data().worldstate().fetch::<Cetus>().await?;
+
+    let mut calendar = Calendar::new(t!(lang, "calendar.cetus_nights.name"));
+
+    for night_start in night_starts(&cetus).take_while(|start| *start < until) {
+        calendar.push(Event {
+            uid: format!("cetus-night-{}@gaia", night_start.timestamp()),
+            summary: t!(lang, "calendar.cetus_nights.summary").to_owned(),
+            description: Some(t!(lang, "notifier.eidolons.description").to_owned()),
+            location: Some(t!(lang, "worldstate.cetus").to_owned()),
+            start: night_start,
+            end: night_start + CETUS_NIGHT,
+        });
+    }
+
+    send_calendar(ctx, prefs, calendar, "cetus-nights.ics").await
+}
+
+async fn send_calendar(
+    ctx: Context<'_>,
+    prefs: Preferences,
+    calendar: Calendar,
+    filename: &str,
+) -> CmdRet {
+    let lang = prefs.language;
+
+    if calendar.is_empty() {
+        ctx.say(t!(lang, "calendar.empty")).await?;
+        return Ok(());
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .content(t!(lang, "calendar.exported", count = calendar.len()))
+            .attachment(CreateAttachment::bytes(calendar.render(), filename))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use chrono::{DateTime, Utc};
+use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{
+    TimedEvent,
+    queryable::{DailyDeal, FlashSale},
+};
+
+use crate::{CmdRet, Context, i18n::t, settings::Preferences, utils::ContextExt};
+
+/// A platinum offer of Darvo or the market, as shown in embeds.
+#[derive(Debug, Clone)]
+pub struct Deal {
+    pub item: String,
+    pub original_price: Option<i64>,
+    pub sale_price: i64,
+    pub discount: i64,
+    /// Unlimited for flash sales.
+    pub stock: Option<i64>,
+    pub expiry: DateTime<Utc>,
+}
+
+impl From<&DailyDeal> for Deal {
+    fn from(deal: &DailyDeal) -> Self {
+        Self {
+            item: deal.item.clone(),
+            original_price: Some(deal.original_price.into()),
+            sale_price: deal.sale_price.into(),
+            discount: deal.discount.into(),
+            stock: Some(i64::from(deal.total) - i64::from(deal.sold)),
+            expiry: deal.expiry(),
+        }
+    }
+}
+
+impl Deal {
+    /// Converts a flash sale, or returns `None` if it is not sold for platinum.
+    pub fn from_flash_sale(sale: &FlashSale) -> Option<Self> {
+        let sale_price = i64::from(sale.premium_override);
+        let discount = i64::from(sale.discount);
+
+        if sale_price <= 0 {
+            return None;
+        }
+
+        Some(Self {
+            item: sale.item.clone(),
+            // the API only has the sale price, so the original one is derived from the discount
+            original_price: (discount > 0 && discount < 100)
+                .then(|| (sale_price * 100 + (100 - discount) / 2) / (100 - discount)),
+            sale_price,
+            discount,
+            stock: None,
+            expiry: sale.expiry(),
+        })
+    }
+}
+
+pub fn deal_embed(prefs: &Preferences, title: &str, deal: &Deal) -> CreateEmbed {
+    let lang = prefs.language;
+    let platinum = prefs.platinum();
+
+    let price = match deal.original_price {
+        Some(original) => format!("~~{original}~~ **{}** {platinum}", deal.sale_price),
+        None => format!("**{}** {platinum}", deal.sale_price),
+    };
+
+    prefs
+        .embed()
+        .title(title)
+        .description(format!("**{}**", deal.item))
+        .field(t!(lang, "darvo.price"), price, true)
+        .field(
+            t!(lang, "darvo.discount"),
+            format!("{}%", deal.discount),
+            true,
+        )
+        .field(
+            t!(lang, "darvo.stock"),
+            deal.stock
+                .map(|stock| stock.to_string())
+                .unwrap_or_else(|| t!(lang, "darvo.unlimited").to_owned()),
+            true,
+        )
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(deal.expiry),
+            false,
+        )
+}
+
+/// Shows Darvo's current daily deal
+#[command(slash_command)]
+pub async fn darvo(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let deals = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<DailyDeal>(lang.into())
+        .await?;
+
+    let Some(deal) = deals.first() else {
+        ctx.say(t!(lang, "darvo.no_deal")).await?;
+        return Ok(());
+    };
+
+    ctx.send(
+        CreateReply::default()
+            .embed(deal_embed(&prefs, t!(lang, "darvo.title"), &deal.into()))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use chrono::{Datelike, Days, Utc};
+use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{TimedEvent, queryable::DuviriCycle};
+
+use crate::{
+    CmdRet,
+    Context,
+    i18n::{self, t},
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// The choices of the normal Circuit, which are Warframes.
+pub fn circuit_warframes(cycle: &DuviriCycle) -> &[String] {
+    circuit_choices(cycle, "normal")
+}
+
+/// The choices of the Steel Path Circuit, which are Incarnon Genesis adapters.
+pub fn circuit_incarnons(cycle: &DuviriCycle) -> &[String] {
+    circuit_choices(cycle, "hard")
+}
+
+fn circuit_choices<'a>(cycle: &'a DuviriCycle, category: &str) -> &'a [String] {
+    cycle
+        .choices
+        .iter()
+        .find(|choice| choice.category == category)
+        .map(|choice| choice.choices.as_slice())
+        .unwrap_or_default()
+}
+
+fn list(choices: &[String]) -> String {
+    if choices.is_empty() {
+        return "-".to_owned();
+    }
+
+    choices
+        .iter()
+        .map(|choice| format!("- {choice}"))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn create_duviri_embed(prefs: &Preferences, cycle: &DuviriCycle) -> CreateEmbed {
+    let lang = prefs.language;
+
+    // the Circuit rotates every Monday at midnight UTC
+    let today = Utc::now().date_naive();
+    let circuit_reset = today
+        .checked_add_days(Days::new(
+            7 - u64::from(today.weekday().num_days_from_monday()),
+        ))
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+        .unwrap_or_else(Utc::now);
+
+    let mood = i18n::translate(
+        lang,
+        &format!("duviri.mood.{}", cycle.state.to_string().to_lowercase()),
+    )
+    .to_owned();
+
+    prefs
+        .embed()
+        .title(t!(lang, "duviri.title"))
+        .field(t!(lang, "duviri.mood"), mood, true)
+        .field(
+            t!(lang, "duviri.mood_changes"),
+            prefs.format_time(cycle.expiry()),
+            true,
+        )
+        .field(
+            t!(lang, "duviri.circuit_warframes"),
+            list(circuit_warframes(cycle)),
+            false,
+        )
+        .field(
+            t!(lang, "duviri.circuit_incarnons"),
+            list(circuit_incarnons(cycle)),
+            false,
+        )
+        .field(
+            t!(lang, "duviri.circuit_resets"),
+            prefs.format_time(circuit_reset),
+            false,
+        )
+        .thumbnail("https://wiki.warframe.com/images/thumb/Duviri.png/300px-Duviri.png")
+}
+
+/// Shows the current Circuit rotations and the spiral mood of Duviri
+#[command(slash_command)]
+pub async fn duviri(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let cycle = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<DuviriCycle>(prefs.language.into())
+        .await?;
+
+    ctx.send(
+        CreateReply::default()
+            .embed(create_duviri_embed(&prefs, &cycle))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,171 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{CetusState, TimedEvent, queryable::Cetus};
+
+use crate::{
+    CmdRet,
+    Context,
+    i18n::t,
+    lfg::{LfgActivity, Squad, commands::post_squad},
+    settings::{Preferences, model::TimeFormat},
+    utils::ContextExt,
+};
+
+/// Length of a night on the Plains of Eidolon.
+pub const CETUS_NIGHT: TimeDelta = TimeDelta::minutes(50);
+
+/// Length of a full day/night cycle on the Plains of Eidolon.
+pub const CETUS_CYCLE: TimeDelta = TimeDelta::minutes(150);
+
+/// How much night an average squad needs to capture all three Eidolons.
+const TRIPLE_CAP_TIME: TimeDelta = TimeDelta::minutes(25);
+
+const DEFAULT_NIGHTS: u32 = 5;
+
+const SQUAD_SIZE: i64 = 4;
+
+/// The start of every night from the current one (or the next, during the day) onwards.
+///
+/// The cycle is fixed, so every night can be derived from the current state.
+pub fn night_starts(cetus: &Cetus) -> impl Iterator<Item = DateTime<Utc>> {
+    let first = match cetus.state {
+        CetusState::Night => cetus.expiry() - CETUS_NIGHT,
+        CetusState::Day => cetus.expiry(),
+    };
+
+    (0..).map(move |n| first + CETUS_CYCLE * n)
+}
+
+fn create_eidolons_embed(prefs: &Preferences, cetus: &Cetus, nights: u32) -> CreateEmbed {
+    let lang = prefs.language;
+    let now = Utc::now();
+
+    // the planner always shows both, as nights are planned ahead and joined on short notice
+    let both = Preferences {
+        time_format: TimeFormat::Both,
+        ..*prefs
+    };
+
+    let current = match cetus.state {
+        CetusState::Night => {
+            let left = cetus.expiry() - now;
+            let triple_cap = if left >= TRIPLE_CAP_TIME {
+                t!(lang, "eidolons.triple_cap_possible")
+            } else {
+                t!(lang, "eidolons.triple_cap_impossible")
+            };
+
+            format!(
+                "{}\n{}",
+                t!(
+                    lang,
+                    "eidolons.night_left",
+                    minutes = left.num_minutes(),
+                    time = both.format_time(cetus.expiry())
+                ),
+                triple_cap
+            )
+        },
+        CetusState::Day => {
+            t!(
+                lang,
+                "eidolons.next_night",
+                time = both.format_time(cetus.expiry())
+            )
+        },
+    };
+
+    let upcoming = night_starts(cetus)
+        .filter(|start| *start > now)
+        .take(nights as usize)
+        .enumerate()
+        .map(|(idx, start)| format!("{}. {}", idx + 1, both.format_time(start)))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    prefs
+        .embed()
+        .title(t!(lang, "eidolons.title"))
+        .description(current)
+        .field(t!(lang, "eidolons.upcoming"), upcoming, false)
+        .field(t!(lang, "eidolons.tips_title"), t!(lang, "eidolons.tips"), false)
+        .thumbnail(
+            "https://wiki.warframe.com/images/thumb/Plains_of_Eidolon.png/300px-Plains_of_Eidolon.png?c7c8c",
+        )
+}
+
+/// Plans Eidolon hunts: upcoming nights, night left and optionally a squad for one of the nights
+#[command(slash_command)]
+pub async fn eidolons(
+    ctx: Context<'_>,
+    #[description = "How many upcoming nights to list (default: 5)"]
+    #[min = 1]
+    #[max = 20]
+    nights: Option<u32>,
+    #[description = "Host a squad for this night from the list, e.g. 1 for the next night"]
+    #[min = 1]
+    #[max = 20]
+    host_night: Option<u32>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let nights = nights.unwrap_or(DEFAULT_NIGHTS);
+
+    // checked before replying, so a rejected squad doesn't leave the planner without an error
+    let host = match host_night {
+        Some(night) => {
+            // squads are posted in a guild channel for everyone to join
+            let guild_id = ctx
+                .guild_id()
+                .ok_or(t!(lang, "eidolons.squad_guild_only"))?;
+
+            if night > nights {
+                return Err(t!(
+                    lang,
+                    "eidolons.night_not_listed",
+                    night = night,
+                    nights = nights
+                )
+                .into());
+            }
+
+            Some((guild_id, night))
+        },
+        None => None,
+    };
+
+    let cetus = ctx.data().worldstate().fetch::<Cetus>().await?;
+
+    ctx.send(
+        CreateReply::default()
+            .embed(create_eidolons_embed(&prefs, &cetus, nights))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    let Some((guild_id, night)) = host else {
+        return Ok(());
+    };
+
+    // the same nights the planner lists, which are numbered from 1
+    let Some(dusk) = night_starts(&cetus)
+        .filter(|start| *start > Utc::now())
+        .nth(night as usize - 1)
+    else {
+        return Ok(());
+    };
+
+    let squad = Squad {
+        id: 0,
+        server_id: guild_id.get() as i64,
+        channel_id: ctx.channel_id().get() as i64,
+        message_id: None,
+        owner_id: ctx.author().id.get() as i64,
+        activity: LfgActivity::EidolonHunt,
+        note: None,
+        starts_at: dusk,
+        slots: SQUAD_SIZE,
+    };
+
+    post_squad(ctx, squad).await
+}
@@ -1,77 +1,136 @@
-use std::{sync::Arc, time::Duration};
-
 use indoc::formatdoc;
 use itertools::Itertools;
-use poise::{CreateReply, command, serenity_prelude::CreateEmbed};
-use poise_paginator::{CancellationType, paginate};
-use warframe::worldstate::queryable::Event;
-
-use crate::{CmdRet, Context, Error, emojis, utils};
-
-async fn generate_embed(
-    _ctx: Context<'_>,
-    idx: usize,
-    _cancellation_type: CancellationType,
-    state: Arc<[CreateEmbed]>,
-) -> Result<CreateEmbed, Error> {
-    Ok(state[idx].clone())
+use poise::{command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{Reward, TimedEvent, queryable::Event};
+
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    components::{ComponentState, Page, send_pages},
+    i18n::t,
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// A single reward as `item + 3x counted item + credits`, or an empty string if it is empty.
+pub fn format_reward(prefs: &Preferences, reward: &Reward) -> String {
+    reward
+        .items
+        .iter()
+        .cloned()
+        .chain(
+            reward
+                .counted_items
+                .iter()
+                .map(|item| format!("{}x {}", item.count, item.ty)),
+        )
+        .chain((reward.credits > 0).then(|| format!("{} {}", reward.credits, prefs.credits())))
+        .join(" + ")
 }
 
-#[command(slash_command)]
-pub async fn events(ctx: Context<'_>) -> CmdRet {
-    let events = ctx.data().worldstate().fetch::<Event>().await?;
-
-    let embeds = events
-        .into_iter()
-        .map(|event| {
-            utils::embed()
-                .title(event.description.as_deref().unwrap_or("-"))
-                .description(formatdoc! {
-                    "
-                    **Missions**
-                    **`{}`**
-                    **Total Rewards**
-                    {}
-                    ",
-                    event.concurrent_nodes.len(),
-                    event.rewards.into_iter()
-                        .filter(|reward|
-                            !reward.counted_items.is_empty()
-                            || !reward.items.is_empty()
-                            || reward.credits > 0
-                        )
-                        .map(|reward|
-                            format!(
-                                "- {}{}",
-                                reward.items.join(", "),
-                                if reward.credits > 0 {
-                                    format!(" + {} {}", reward.credits, emojis::CREDITS)
-                                } else {
-                                    String::new()
-                                }
-                        )
-                    ).join("\n")
-                })
-        })
-        .collect::<Arc<[_]>>();
-
-    if embeds.is_empty() {
-        return Err("No events found".into());
+/// The names of every item of `reward`, counted items included.
+pub fn reward_items(reward: &Reward) -> impl Iterator<Item = &str> {
+    reward
+        .items
+        .iter()
+        .map(String::as_str)
+        .chain(reward.counted_items.iter().map(|item| item.ty.as_str()))
+}
+
+/// Every reward of `event`, including counted items such as Nitain Extract.
+fn format_rewards(prefs: &Preferences, event: &Event) -> String {
+    event
+        .rewards
+        .iter()
+        .map(|reward| format_reward(prefs, reward))
+        .filter(|reward| !reward.is_empty())
+        .map(|reward| format!("- {reward}"))
+        .join("\n")
+}
+
+/// The progress of `event`, if it has a score or a health bar.
+fn format_progress(prefs: &Preferences, event: &Event) -> Option<String> {
+    let lang = prefs.language;
+
+    if let Some(health) = event.health {
+        return Some(t!(lang, "events.health", health = format!("{health:.2}")));
     }
 
-    if embeds.len() == 1 {
-        ctx.send(CreateReply::default().embed(embeds[0].clone()))
-            .await?;
-    } else {
-        paginate(
-            ctx,
-            generate_embed,
-            embeds.len(),
-            Duration::from_secs(60),
-            embeds,
+    event.maximum_score.map(|maximum| {
+        let current = event.current_score.unwrap_or_default();
+        t!(
+            lang,
+            "events.score",
+            current = current,
+            maximum = maximum,
+            percent = format!(
+                "{:.1}",
+                f64::from(current) / f64::from(maximum.max(1)) * 100.0
+            )
         )
-        .await?;
+    })
+}
+
+/// The embed describing `event`, shared by `/events` and the event tracker.
+pub fn event_embed(prefs: &Preferences, event: &Event) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let mut description = formatdoc! {
+        "
+        **{}**
+        **`{}`**
+        ",
+        t!(lang, "events.missions"),
+        event.concurrent_nodes.len(),
+    };
+
+    if let Some(progress) = format_progress(prefs, event) {
+        description.push_str(&format!(
+            "**{}**\n{progress}\n",
+            t!(lang, "events.progress")
+        ));
     }
 
-    Ok(())
+    description.push_str(&format!(
+        "**{}**\n{}\n**{}** {}",
+        t!(lang, "events.total_rewards"),
+        format_rewards(prefs, event),
+        t!(lang, "common.ends"),
+        prefs.format_time(event.expiry())
+    ));
+
+    prefs
+        .embed()
+        .title(event.description.as_deref().unwrap_or("-"))
+        .description(description)
+}
+
+/// The event at `index`, or the last one if fewer events are running by now.
+pub async fn events_page(data: &AppData, prefs: &Preferences, index: usize) -> Result<Page, Error> {
+    let lang = prefs.language;
+    let events = data
+        .worldstate()
+        .fetch_using_lang::<Event>(lang.into())
+        .await?;
+
+    let Some(last) = events.len().checked_sub(1) else {
+        return Err(t!(lang, "events.none").into());
+    };
+
+    let index = index.min(last);
+
+    Ok(Page::new(
+        event_embed(prefs, &events[index]),
+        index,
+        events.len(),
+    ))
+}
+
+#[command(slash_command)]
+pub async fn events(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+
+    send_pages(ctx, &prefs, ComponentState::Events).await
 }
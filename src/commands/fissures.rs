@@ -0,0 +1,313 @@
+use itertools::Itertools;
+use poise::{
+    ChoiceParameter,
+    command,
+    serenity_prelude::{AutocompleteChoice, CreateEmbed},
+};
+use serde::{Deserialize, Serialize};
+use warframe::worldstate::{Tier, TimedEvent, queryable::Fissure};
+
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    components::{ComponentState, Page, send_pages},
+    i18n::{LocalizedChoice, t},
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// Three inline fields per fissure, so a page stays below Discord's limit of 25 fields.
+const FISSURES_PER_PAGE: usize = 8;
+
+/// The tier of a fissure. The game uses the same names in every language.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    sqlx::Type,
+    ChoiceParameter,
+)]
+#[repr(i64)]
+pub enum FissureTier {
+    #[name = "Lith"]
+    #[name_localized("de", "Lith")]
+    #[name_localized("fr", "Lith")]
+    Lith,
+
+    #[name = "Meso"]
+    #[name_localized("de", "Meso")]
+    #[name_localized("fr", "Meso")]
+    Meso,
+
+    #[name = "Neo"]
+    #[name_localized("de", "Neo")]
+    #[name_localized("fr", "Neo")]
+    Neo,
+
+    #[name = "Axi"]
+    #[name_localized("de", "Axi")]
+    #[name_localized("fr", "Axi")]
+    Axi,
+
+    #[name = "Requiem"]
+    #[name_localized("de", "Requiem")]
+    #[name_localized("fr", "Requiem")]
+    Requiem,
+
+    #[name = "Omnia"]
+    #[name_localized("de", "Omnia")]
+    #[name_localized("fr", "Omnia")]
+    Omnia,
+}
+
+impl From<&Tier> for FissureTier {
+    fn from(tier: &Tier) -> Self {
+        match tier {
+            Tier::Lith => Self::Lith,
+            Tier::Meso => Self::Meso,
+            Tier::Neo => Self::Neo,
+            Tier::Axi => Self::Axi,
+            Tier::Requiem => Self::Requiem,
+            Tier::Omnia => Self::Omnia,
+        }
+    }
+}
+
+/// The Railjack regions Void Storms appear in.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ChoiceParameter,
+)]
+#[repr(i64)]
+pub enum ProximaRegion {
+    #[name = "Earth Proxima"]
+    #[name_localized("de", "Erde-Proxima")]
+    #[name_localized("fr", "Proxima de la Terre")]
+    Earth,
+
+    #[name = "Venus Proxima"]
+    #[name_localized("de", "Venus-Proxima")]
+    #[name_localized("fr", "Proxima de Vénus")]
+    Venus,
+
+    #[name = "Saturn Proxima"]
+    #[name_localized("de", "Saturn-Proxima")]
+    #[name_localized("fr", "Proxima de Saturne")]
+    Saturn,
+
+    #[name = "Neptune Proxima"]
+    #[name_localized("de", "Neptun-Proxima")]
+    #[name_localized("fr", "Proxima de Neptune")]
+    Neptune,
+
+    #[name = "Pluto Proxima"]
+    #[name_localized("de", "Pluto-Proxima")]
+    #[name_localized("fr", "Proxima de Pluton")]
+    Pluto,
+
+    #[name = "Veil Proxima"]
+    #[name_localized("de", "Schleier-Proxima")]
+    #[name_localized("fr", "Proxima du Voile")]
+    Veil,
+}
+
+impl ProximaRegion {
+    /// The region of a Void Storm, read from its English node name such as
+    /// `Korm's Belt (Earth Proxima)`.
+    pub fn of(fissure: &Fissure) -> Option<Self> {
+        let (_, region) = fissure.node_key.rsplit_once('(')?;
+
+        match region.trim_end_matches(')').trim_end_matches(" Proxima") {
+            "Earth" => Some(Self::Earth),
+            "Venus" => Some(Self::Venus),
+            "Saturn" => Some(Self::Saturn),
+            "Neptune" => Some(Self::Neptune),
+            "Pluto" => Some(Self::Pluto),
+            "Veil" => Some(Self::Veil),
+            _ => None,
+        }
+    }
+}
+
+/// Adds the node, mission type and expiry of `fissure` as inline fields, like the
+/// Steel Path Disruption notifier does.
+pub fn add_fissure_fields(
+    prefs: &Preferences,
+    embed: CreateEmbed,
+    fissure: &Fissure,
+) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let mut node = fissure.node.clone();
+    if fissure.is_hard {
+        node.push_str(&format!(" ({})", t!(lang, "fissures.steel_path")));
+    }
+    if fissure.is_storm {
+        node.push_str(&format!(" ({})", t!(lang, "fissures.void_storm")));
+    }
+
+    embed
+        .field(t!(lang, "arbi.node"), node, true)
+        .field(t!(lang, "arbi.mission_type"), &fissure.mission_type, true)
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(fissure.expiry()),
+            true,
+        )
+}
+
+/// Autocompletes the mission types of the currently active fissures.
+async fn autocomplete_mission_type(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let lang = ctx
+        .preferences()
+        .await
+        .map(|prefs| prefs.language)
+        .unwrap_or_default();
+    let Ok(fissures) = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<Fissure>(lang.into())
+        .await
+    else {
+        return Vec::new();
+    };
+    let partial = partial.to_lowercase();
+
+    fissures
+        .iter()
+        .map(|fissure| fissure.mission_type.as_str())
+        .filter(|mission_type| mission_type.to_lowercase().contains(&partial))
+        .sorted()
+        .dedup()
+        .take(25)
+        .map(|mission_type| AutocompleteChoice::new(mission_type, mission_type))
+        .collect()
+}
+
+/// The filters behind the pages of `/fissures` and `/void-storms`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FissureQuery {
+    tier: Option<FissureTier>,
+    /// In the language of the user who ran the command.
+    mission_type: Option<String>,
+    steel_path: Option<bool>,
+    void_storm: Option<bool>,
+    region: Option<ProximaRegion>,
+}
+
+impl FissureQuery {
+    fn matches(&self, fissure: &Fissure) -> bool {
+        self.tier
+            .is_none_or(|tier| FissureTier::from(&fissure.tier) == tier)
+            && self
+                .mission_type
+                .as_ref()
+                .is_none_or(|mission_type| fissure.mission_type.eq_ignore_ascii_case(mission_type))
+            && self
+                .steel_path
+                .is_none_or(|steel_path| fissure.is_hard == steel_path)
+            && self
+                .void_storm
+                .is_none_or(|void_storm| fissure.is_storm == void_storm)
+            && self
+                .region
+                .is_none_or(|region| ProximaRegion::of(fissure) == Some(region))
+    }
+
+    /// The page at `index`, one tier per page and sorted by expiry, or the last page if fewer
+    /// fissures are left by now.
+    pub async fn page(
+        &self,
+        data: &AppData,
+        prefs: &Preferences,
+        index: usize,
+    ) -> Result<Page, Error> {
+        let lang = prefs.language;
+        let fissures = data
+            .worldstate()
+            .fetch_using_lang::<Fissure>(lang.into())
+            .await?;
+
+        let mut matching = fissures
+            .iter()
+            .filter(|fissure| self.matches(fissure))
+            .collect::<Vec<_>>();
+        matching.sort_by_key(|fissure| (FissureTier::from(&fissure.tier), fissure.expiry()));
+
+        let pages = matching
+            .chunk_by(|a, b| a.tier == b.tier)
+            .flat_map(|group| group.chunks(FISSURES_PER_PAGE))
+            .collect::<Vec<_>>();
+
+        let Some(last) = pages.len().checked_sub(1) else {
+            return Err(t!(lang, "fissures.none").into());
+        };
+
+        let index = index.min(last);
+        let page = pages[index];
+
+        let embed = prefs.embed().title(t!(
+            lang,
+            "fissures.title",
+            tier = FissureTier::from(&page[0].tier).name_in(lang)
+        ));
+        let embed = page.iter().fold(embed, |embed, fissure| {
+            add_fissure_fields(prefs, embed, fissure)
+        });
+
+        Ok(Page::new(embed, index, pages.len()))
+    }
+}
+
+/// Lists the active Void Fissures, grouped by tier
+#[command(slash_command)]
+pub async fn fissures(
+    ctx: Context<'_>,
+    #[description = "Only show fissures of this tier"] tier: Option<FissureTier>,
+    #[description = "Only show this mission type"]
+    #[autocomplete = autocomplete_mission_type]
+    mission_type: Option<String>,
+    #[description = "Only show Steel Path fissures (true) or normal ones (false)"]
+    steel_path: Option<bool>,
+    #[description = "Only show Void Storms (true) or star chart fissures (false)"]
+    void_storm: Option<bool>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+
+    let query = FissureQuery {
+        tier,
+        mission_type,
+        steel_path,
+        void_storm,
+        region: None,
+    };
+
+    send_pages(ctx, &prefs, ComponentState::Fissures(query)).await
+}
+
+/// Lists the active Void Storms, grouped by tier
+#[command(slash_command, rename = "void-storms")]
+pub async fn void_storms(
+    ctx: Context<'_>,
+    #[description = "Only show Void Storms of this tier"] tier: Option<FissureTier>,
+    #[description = "Only show Void Storms in this Proxima"] region: Option<ProximaRegion>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+
+    let query = FissureQuery {
+        tier,
+        mission_type: None,
+        steel_path: None,
+        void_storm: Some(true),
+        region,
+    };
+
+    send_pages(ctx, &prefs, ComponentState::Fissures(query)).await
+}
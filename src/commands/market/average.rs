@@ -6,9 +6,9 @@ use crate::{
     CmdRet,
     Context,
     Error,
-    commands::market::{I18nEn, find_best_matches, market_url},
-    emojis,
-    utils,
+    commands::market::{Localized, find_best_matches, market_url},
+    i18n::{Language, t},
+    utils::ContextExt,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
@@ -34,7 +34,11 @@ impl From<Vec<Statistic>> for StatisticInfo {
     }
 }
 
-async fn get_statistics(ctx: Context<'_>, item_slug: &str) -> Result<Statistics, Error> {
+async fn get_statistics(
+    ctx: Context<'_>,
+    lang: Language,
+    item_slug: &str,
+) -> Result<Statistics, Error> {
     let url = format!("https://api.warframe.market/v1/items/{item_slug}/statistics");
 
     if let Some(statistics) = ctx.data().market_statistic_cache().get(&url).await {
@@ -44,7 +48,7 @@ async fn get_statistics(ctx: Context<'_>, item_slug: &str) -> Result<Statistics,
     let response = reqwest::get(&url).await?;
 
     if response.status() == StatusCode::NOT_FOUND {
-        return Err("Item not found".into());
+        return Err(t!(lang, "market.item_not_found").into());
     }
 
     let statistics = response.json::<Statistics>().await?;
@@ -88,8 +92,11 @@ pub async fn average(
     item_slug: String,
     #[description = "Mod Rank of the item, if applicable. Defaults to 0."] mod_rank: Option<i32>,
 ) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+
     // Multiple statisticS for a single item
-    let statistics = get_statistics(ctx, &item_slug)
+    let statistics = get_statistics(ctx, lang, &item_slug)
         .await?
         .payload
         .statistics_closed
@@ -102,13 +109,17 @@ pub async fn average(
         .await?
         .expect("Item should be found");
 
-    let item_name = item.i18n_en().name.as_str();
+    let item_name = item.localized(lang).name.as_str();
 
     if statistics.is_empty() {
         ctx.send(
-            CreateReply::default().embed(
-                utils::embed().description(format!("No statistics found for `{item_name}`")),
-            ),
+            CreateReply::default()
+                .embed(prefs.embed().description(t!(
+                    lang,
+                    "market.no_statistics",
+                    item = item_name
+                )))
+                .ephemeral(prefs.ephemeral),
         )
         .await?;
 
@@ -123,8 +134,9 @@ pub async fn average(
     let statistic_info = StatisticInfo::from(statistics);
 
     ctx.send(
-        CreateReply::default().embed(
-            utils::embed()
+        CreateReply::default().ephemeral(prefs.ephemeral).embed(
+            prefs
+                .embed()
                 .title(format!(
                     "{}{}",
                     item_name,
@@ -136,30 +148,30 @@ pub async fn average(
                 ))
                 .url(market_url(&item_slug))
                 .field(
-                    "Average",
-                    format!("**`{}`** {}", statistic_info.average, emojis::PLATINUM),
+                    t!(lang, "market.average"),
+                    format!("**`{}`** {}", statistic_info.average, prefs.platinum()),
                     false,
                 )
                 .field(
-                    "Moving Average",
+                    t!(lang, "market.moving_average"),
                     format!(
                         "**`{}`** {}",
                         statistic_info
                             .moving_avg
                             .map(|avg| avg.to_string())
-                            .unwrap_or_else(|| "N/A".into()),
-                        emojis::PLATINUM
+                            .unwrap_or_else(|| t!(lang, "market.not_available").into()),
+                        prefs.platinum()
                     ),
                     false,
                 )
                 .field(
-                    "Sales (Last 48 hours)",
+                    t!(lang, "market.sales_48h"),
                     format!("**`{}`**", statistic_info.amount_sold),
                     false,
                 )
                 .thumbnail(format!(
                     "https://warframe.market/static/assets/{}",
-                    item.i18n_en().icon
+                    item.localized(lang).icon
                 )),
         ),
     )
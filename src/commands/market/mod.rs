@@ -2,9 +2,9 @@ use std::cmp::Reverse;
 
 use poise::{command, serenity_prelude::AutocompleteChoice};
 use strsim::jaro_winkler;
-use warframe::market::{Item, ItemI18N, ItemShort, ItemShortI18N, Language};
+use warframe::market::{self, Item, ItemI18N, ItemShort, ItemShortI18N};
 
-use crate::{CmdRet, Context};
+use crate::{CmdRet, Context, i18n::Language, utils::ContextExt};
 
 pub mod average;
 pub mod orders;
@@ -15,14 +15,16 @@ pub async fn market(_ctx: Context<'_>) -> CmdRet {
 }
 
 async fn find_best_matches(ctx: Context<'_>, query: &str) -> Vec<AutocompleteChoice> {
+    let lang = ctx.preferences().await.unwrap_or_default().language;
+
     // Get all candidate items first
-    let candidates = ctx.data().market().items(Language::En).await.unwrap();
+    let candidates = ctx.data().market().items(lang.into()).await.unwrap();
 
     // 1. Map each candidate to a tuple containing its score and a reference to it
     let mut scored_candidates: Vec<_> = candidates
         .iter()
         .map(|candidate| {
-            let name = &candidate.i18n_en().name;
+            let name = &candidate.localized(lang).name;
             let score = (jaro_winkler(query, name) * 1000.0) as i32;
             (score, candidate)
         })
@@ -37,7 +39,7 @@ async fn find_best_matches(ctx: Context<'_>, query: &str) -> Vec<AutocompleteCho
         .into_iter()
         .take(25)
         .map(|(_score, candidate)| {
-            AutocompleteChoice::new(&candidate.i18n_en().name, candidate.slug.clone())
+            AutocompleteChoice::new(&candidate.localized(lang).name, candidate.slug.clone())
         })
         .collect::<Vec<_>>()
 }
@@ -50,18 +52,25 @@ pub fn profile_url(username: &str) -> String {
     format!("https://warframe.market/profile/{username}")
 }
 
-pub trait I18nEn<Item> {
-    fn i18n_en(&self) -> &Item;
+/// Picks the item translation for a [`Language`], falling back to English.
+pub trait Localized<Item> {
+    fn localized(&self, lang: Language) -> &Item;
 }
 
-impl I18nEn<ItemI18N> for Item {
-    fn i18n_en(&self) -> &ItemI18N {
-        self.i18n.get(&Language::En).unwrap()
+impl Localized<ItemI18N> for Item {
+    fn localized(&self, lang: Language) -> &ItemI18N {
+        self.i18n
+            .get(&market::Language::from(lang))
+            .or_else(|| self.i18n.get(&market::Language::En))
+            .unwrap()
     }
 }
 
-impl I18nEn<ItemShortI18N> for ItemShort {
-    fn i18n_en(&self) -> &ItemShortI18N {
-        self.i18n.get(&Language::En).unwrap()
+impl Localized<ItemShortI18N> for ItemShort {
+    fn localized(&self, lang: Language) -> &ItemShortI18N {
+        self.i18n
+            .get(&market::Language::from(lang))
+            .or_else(|| self.i18n.get(&market::Language::En))
+            .unwrap()
     }
 }
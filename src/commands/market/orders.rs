@@ -1,5 +1,3 @@
-use std::{sync::Arc, time::Duration};
-
 use indoc::formatdoc;
 use itertools::Itertools;
 use poise::{
@@ -12,29 +10,29 @@ use poise::{
         colours::roles::DARK_RED,
     },
 };
-use poise_paginator::{CancellationType, paginate};
-use warframe::market::{Item, Language, OrderType, Status, queryable::OrderWithUser};
+use serde::{Deserialize, Serialize};
+use warframe::market::{Item, OrderType, Status, queryable::OrderWithUser};
 
 use crate::{
+    AppData,
     CmdRet,
     Context,
     Error,
-    commands::market::{I18nEn, find_best_matches, market_url, profile_url},
-    emojis,
-    utils::{self, ApplyIf},
+    commands::market::{Localized, find_best_matches, market_url, profile_url},
+    components::{ComponentState, Page, send_pages},
+    i18n::{Language, t},
+    settings::Preferences,
+    utils::{ApplyIf, ContextExt},
 };
 
-async fn generate_order_embed(
-    _ctx: Context<'_>,
-    idx: usize,
-    _cancellation_type: CancellationType,
-    (orders, item): (Arc<[OrderWithUser]>, Arc<Item>),
-) -> Result<CreateEmbed, Error> {
-    let order = &orders[idx].order;
-    let user = &orders[idx].user;
-    let item_name = item.i18n_en().name.as_str();
-
-    let embed = utils::embed()
+fn generate_order_embed(prefs: &Preferences, order: &OrderWithUser, item: &Item) -> CreateEmbed {
+    let lang = prefs.language;
+    let user = &order.user;
+    let order = &order.order;
+    let item_name = item.localized(lang).name.as_str();
+
+    prefs
+        .embed()
         .author(
             CreateEmbedAuthor::new(&user.ingame_name).icon_url(
                 user.avatar
@@ -49,16 +47,20 @@ async fn generate_order_embed(
         .title(item_name)
         .url(market_url(&item.slug))
         .field(
-            "Price",
-            format!("**`{}`** {}", order.platinum, emojis::PLATINUM),
+            t!(lang, "market.price"),
+            format!("**`{}`** {}", order.platinum, prefs.platinum()),
+            true,
+        )
+        .field(
+            t!(lang, "market.quantity"),
+            format!("**`{}`**", order.quantity),
             true,
         )
-        .field("Quantity", format!("**`{}`**", order.quantity), true)
         .apply_optionally(order.rank, |embed, rank| {
-            embed.field("Rank", format!("**`{rank}`**"), true)
+            embed.field(t!(lang, "market.rank"), format!("**`{rank}`**"), true)
         })
         .field(
-            "Last Updated",
+            t!(lang, "market.last_updated"),
             FormattedTimestamp::new(
                 order.updated_at.into(),
                 Some(FormattedTimestampStyle::ShortDateTime),
@@ -67,26 +69,105 @@ async fn generate_order_embed(
             false,
         )
         .field(
-            "Buy",
+            t!(lang, "market.buy"),
+            // The whisper goes to the seller, so it stays in English like on warframe.market
             format!(
                 "```\n/w {} Hi! I want to buy: \"{}\" for {} platinum. (warframe.market)```",
-                user.ingame_name, item_name, order.platinum
+                user.ingame_name,
+                item.localized(Language::En).name,
+                order.platinum
             ),
             true,
         )
         .field(
-            format!("{}'s Reputation", user.ingame_name),
+            t!(lang, "market.reputation", user = user.ingame_name),
             format!("**`{}`**", user.reputation),
             false,
         )
         .field(
-            format!("{}'s WFM Profile", user.ingame_name),
-            format!("[Profile]({})", profile_url(&user.ingame_name)),
+            t!(lang, "market.profile_title", user = user.ingame_name),
+            format!(
+                "[{}]({})",
+                t!(lang, "market.profile"),
+                profile_url(&user.ingame_name)
+            ),
             true,
         )
-        .apply_if(user.status != Status::Ingame, |embed| embed.color(DARK_RED));
+        .apply_if(user.status != Status::Ingame, |embed| embed.color(DARK_RED))
+}
+
+/// The query behind the pages of `/market orders`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrderQuery {
+    item_slug: String,
+    rank: u8,
+    limit: usize,
+    ingame_only: bool,
+}
+
+impl OrderQuery {
+    /// The order at `index`, or the last one if fewer orders are left by now.
+    ///
+    /// The orders are fetched again for every page, so a page never shows a stale price.
+    pub async fn page(
+        &self,
+        data: &AppData,
+        prefs: &Preferences,
+        index: usize,
+    ) -> Result<Page, Error> {
+        let lang = prefs.language;
+        let market = data.market();
+
+        let Some(orders_with_user) = market
+            .fetch_orders_by_slug(&self.item_slug, lang.into())
+            .await?
+        else {
+            return Err(t!(lang, "market.item_not_found").into());
+        };
+
+        let orders = orders_with_user
+            .into_iter()
+            .filter(|order| {
+                order.order.r#type == OrderType::Sell
+                    && (!self.ingame_only || order.user.status == Status::Ingame)
+                    && order.order.rank.unwrap_or(0) == self.rank
+            })
+            .sorted_by(|a, b| {
+                a.order
+                    .platinum
+                    .cmp(&b.order.platinum)
+                    .then_with(|| b.order.updated_at.cmp(&a.order.updated_at))
+            })
+            .take(self.limit)
+            .collect::<Vec<_>>();
+
+        if orders.is_empty() {
+            return Err(formatdoc!(
+                "
+            **{}**
+
+            {}: `{}`
+            {}: `{}`
+            ",
+                t!(lang, "market.no_orders"),
+                t!(lang, "market.ingame_only"),
+                self.ingame_only,
+                t!(lang, "market.rank"),
+                self.rank,
+            )
+            .into());
+        }
+
+        let item = market
+            .fetch_item(&self.item_slug)
+            .await?
+            .ok_or(t!(lang, "market.item_not_found"))?;
+
+        let index = index.min(orders.len() - 1);
+        let embed = generate_order_embed(prefs, &orders[index], &item);
 
-    Ok(embed)
+        Ok(Page::new(embed, index, orders.len()))
+    }
 }
 
 /// Get a list of SELL orders for a specific item.
@@ -103,68 +184,24 @@ pub async fn orders(
     #[rename = "rank"]
     rank: Option<u8>,
 
-    #[description = "The maximum number of orders to return. Defaults to 20."]
+    #[description = "The maximum number of orders to return. Defaults to the server setting or 20."]
     #[rename = "limit"]
     limit: Option<usize>,
 
-    #[description = "Whether to only include users that are currently ingame. Defaults to true."]
+    #[description = "Whether to only include users that are currently ingame. Defaults to the server setting or true."]
     #[rename = "ingame_only"]
     ingame_only: Option<bool>,
 ) -> CmdRet {
-    let limit = limit.unwrap_or(20);
-    let ingame_only = ingame_only.unwrap_or(true);
-    let rank = rank.unwrap_or(0);
-
-    let market = ctx.data().market();
+    let prefs = ctx.preferences().await?;
 
-    let Some(orders_with_user) = market
-        .fetch_orders_by_slug(&item_slug, Language::En)
-        .await?
-    else {
-        return Err("Item not found".into());
+    let query = OrderQuery {
+        item_slug,
+        rank: rank.unwrap_or(0),
+        limit: limit.unwrap_or(prefs.market_orders_limit),
+        ingame_only: ingame_only.unwrap_or(prefs.market_ingame_only),
     };
 
-    let orders = orders_with_user
-        .into_iter()
-        .filter(|order| {
-            order.order.r#type == OrderType::Sell
-                && (!ingame_only || order.user.status == Status::Ingame)
-                && order.order.rank.unwrap_or(0) == rank
-        })
-        .sorted_by(|a, b| {
-            a.order
-                .platinum
-                .cmp(&b.order.platinum)
-                .then_with(|| b.order.updated_at.cmp(&a.order.updated_at))
-        })
-        .take(limit)
-        .collect::<Arc<[_]>>();
-
-    if orders.is_empty() {
-        return Err(formatdoc!(
-            "
-        **No orders found for this item.**
-
-        Ingame Only: `{ingame_only}`
-        Rank: `{rank}`
-        "
-        )
-        .into());
-    }
-
-    let item = market
-        .fetch_item(&item_slug)
-        .await?
-        .expect("Item should be found");
-
-    paginate(
-        ctx,
-        generate_order_embed,
-        orders.len(),
-        Duration::from_secs(60),
-        (orders, Arc::new(item)),
-    )
-    .await?;
+    send_pages(ctx, &prefs, ComponentState::MarketOrders(query)).await?;
 
     Ok(())
 }
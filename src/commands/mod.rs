@@ -1,5 +1,17 @@
+pub mod alerts;
 pub mod arbi;
+pub mod arbitration_stats;
 pub mod archon_hunt;
+pub mod bounties;
+pub mod calendar;
+pub mod darvo;
+pub mod duviri;
+pub mod eidolons;
 pub mod event;
+pub mod fissures;
 pub mod market;
+pub mod nightwave;
+pub mod node;
+pub mod owner;
+pub mod steel_path;
 pub mod worldstate;
@@ -0,0 +1,127 @@
+use poise::{command, serenity_prelude::CreateEmbed};
+use warframe::worldstate::{
+    TimedEvent,
+    queryable::{Nightwave, NightwaveChallenge},
+};
+
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    components::{ComponentState, Page, send_pages},
+    i18n::t,
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// The kinds of Nightwave acts, in the order they are paginated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NightwaveCategory {
+    Daily,
+    Weekly,
+    EliteWeekly,
+}
+
+impl NightwaveCategory {
+    const ALL: [Self; 3] = [Self::Daily, Self::Weekly, Self::EliteWeekly];
+
+    pub fn of(challenge: &NightwaveChallenge) -> Self {
+        if challenge.is_daily {
+            Self::Daily
+        } else if challenge.is_elite {
+            Self::EliteWeekly
+        } else {
+            Self::Weekly
+        }
+    }
+
+    pub fn title(self, prefs: &Preferences) -> &'static str {
+        let lang = prefs.language;
+
+        match self {
+            Self::Daily => t!(lang, "nightwave.daily"),
+            Self::Weekly => t!(lang, "nightwave.weekly"),
+            Self::EliteWeekly => t!(lang, "nightwave.elite_weekly"),
+        }
+    }
+}
+
+/// Adds every act of `challenges` as a field showing its standing and expiry.
+pub fn add_challenge_fields<'a>(
+    prefs: &Preferences,
+    mut embed: CreateEmbed,
+    challenges: impl IntoIterator<Item = &'a NightwaveChallenge>,
+) -> CreateEmbed {
+    let lang = prefs.language;
+
+    for challenge in challenges {
+        embed = embed.field(
+            &challenge.title,
+            format!(
+                "{}\n{}\n{} {}",
+                challenge.desc,
+                t!(lang, "nightwave.standing", standing = challenge.reputation),
+                t!(lang, "common.ends"),
+                prefs.format_time(challenge.expiry())
+            ),
+            false,
+        );
+    }
+
+    embed
+}
+
+/// The category at `index` of the active acts, or the last one if fewer are left by now.
+pub async fn nightwave_page(
+    data: &AppData,
+    prefs: &Preferences,
+    index: usize,
+) -> Result<Page, Error> {
+    let lang = prefs.language;
+    let nightwave = data
+        .worldstate()
+        .fetch_using_lang::<Nightwave>(lang.into())
+        .await?;
+
+    let categories = NightwaveCategory::ALL
+        .into_iter()
+        .filter(|category| {
+            nightwave
+                .active_challenges
+                .iter()
+                .any(|challenge| NightwaveCategory::of(challenge) == *category)
+        })
+        .collect::<Vec<_>>();
+
+    let Some(last) = categories.len().checked_sub(1) else {
+        return Err(t!(lang, "nightwave.inactive").into());
+    };
+
+    let index = index.min(last);
+    let category = categories[index];
+
+    let embed = prefs.embed().title(t!(
+        lang,
+        "nightwave.title",
+        category = category.title(prefs)
+    ));
+    let embed = add_challenge_fields(
+        prefs,
+        embed,
+        nightwave
+            .active_challenges
+            .iter()
+            .filter(|challenge| NightwaveCategory::of(challenge) == category),
+    );
+
+    Ok(Page::new(embed, index, categories.len()))
+}
+
+/// Lists the current Nightwave acts, one page per category
+#[command(slash_command)]
+pub async fn nightwave(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+
+    send_pages(ctx, &prefs, ComponentState::Nightwave).await
+}
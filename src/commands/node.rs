@@ -0,0 +1,139 @@
+use poise::{
+    CreateReply,
+    command,
+    serenity_prelude::{AutocompleteChoice, CreateEmbed},
+};
+
+use crate::{
+    CmdRet,
+    Context,
+    game_data::GameData,
+    i18n::t,
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+/// Autocompletes any star chart node by its name in the user's language.
+///
+/// Choices submit the node id, so they resolve regardless of the language.
+async fn autocomplete_any_node(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let lang = ctx
+        .preferences()
+        .await
+        .map(|prefs| prefs.language)
+        .unwrap_or_default();
+    let game_data = ctx.data().game_data();
+    let partial = partial.to_lowercase();
+
+    let mut nodes = game_data
+        .localized_regions(lang)
+        .nodes()
+        .filter(|node| node.name.to_lowercase().contains(&partial))
+        .collect::<Vec<_>>();
+    nodes.sort_by(|a, b| a.name.cmp(&b.name));
+
+    nodes
+        .into_iter()
+        .take(25)
+        .map(|node| {
+            AutocompleteChoice::new(format!("{} ({})", node.name, node.planet), node.id.as_str())
+        })
+        .collect()
+}
+
+/// Builds the detail view of the node `id`, including its place in the Arbitration rotation.
+pub fn node_embed(prefs: &Preferences, game_data: &GameData, id: &str) -> Option<CreateEmbed> {
+    let lang = prefs.language;
+    let regions = game_data.localized_regions(lang);
+    let node = regions.node(id)?;
+    let english = game_data.regions().node(id)?;
+
+    // arbitrations only carry the names of their node, and a name alone does not have to be unique
+    let next = game_data
+        .arbi_data()
+        .iter_upcoming()
+        .map(|(_, arbi)| arbi)
+        .find(|arbi| arbi.node == english.name && arbi.planet == english.planet);
+
+    let count = regions.arbitration_count(id);
+    let frequency = if count == 0 {
+        t!(lang, "node.not_in_rotation").to_owned()
+    } else {
+        let total = regions.schedule_len();
+
+        t!(
+            lang,
+            "node.frequency",
+            count = count,
+            total = total,
+            share = format!("{:.2}", count as f64 / total as f64 * 100.0),
+            // every schedule entry lasts an hour
+            days = format!("{:.1}", total as f64 / count as f64 / 24.0)
+        )
+    };
+
+    Some(
+        prefs
+            .embed()
+            .title(format!("{} ({})", node.name, node.planet))
+            .field(t!(lang, "arbi.mission_type"), &node.mission_type, true)
+            .field(t!(lang, "node.faction"), &node.faction, true)
+            .field(
+                t!(lang, "node.enemy_levels"),
+                format!("{} - {}", node.min_enemy_level, node.max_enemy_level),
+                true,
+            )
+            .field(
+                t!(lang, "node.mastery_req"),
+                node.mastery_req.to_string(),
+                true,
+            )
+            .field(
+                t!(lang, "arbi.tier"),
+                game_data
+                    .node_tier(id)
+                    .map(ToString::to_string)
+                    .unwrap_or_else(|| "-".to_owned()),
+                true,
+            )
+            .field(t!(lang, "node.rotation"), frequency, false)
+            .field(
+                t!(lang, "node.next_arbitration"),
+                next.map(|arbi| prefs.format_time(arbi.activation))
+                    .unwrap_or_else(|| t!(lang, "node.not_scheduled").to_owned()),
+                false,
+            ),
+    )
+}
+
+/// Shows details about a star chart node, including how often it is an Arbitration
+#[command(slash_command)]
+pub async fn node(
+    ctx: Context<'_>,
+    #[description = "The node to look up"]
+    #[autocomplete = autocomplete_any_node]
+    name: String,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let game_data = ctx.data().game_data();
+
+    let id = game_data
+        .localized_regions(lang)
+        .find_node(&name)
+        .or_else(|| game_data.regions().find_node(&name))
+        .map(|node| node.id.clone())
+        .ok_or_else(|| t!(lang, "node.not_found", name = name))?;
+
+    let embed = node_embed(&prefs, &game_data, &id)
+        .ok_or_else(|| t!(lang, "node.not_found", name = name))?;
+
+    ctx.send(
+        CreateReply::default()
+            .embed(embed)
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
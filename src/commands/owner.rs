@@ -0,0 +1,43 @@
+use poise::{CreateReply, command};
+
+use crate::{CmdRet, Context, game_data::data_dir, i18n::t, utils::ContextExt};
+
+// registered globally, so it is hidden from everyone but admins in the command picker
+/// Reloads the Arbitration schedule and data exports without restarting the bot
+#[command(
+    slash_command,
+    rename = "reload-data",
+    owners_only,
+    hide_in_help,
+    default_member_permissions = "ADMINISTRATOR"
+)]
+pub async fn reload_data(ctx: Context<'_>) -> CmdRet {
+    ctx.defer_ephemeral().await?;
+
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+
+    let game_data = ctx.data().reload_game_data().await?;
+
+    let description = match game_data.arbi_data().iter_upcoming().last() {
+        Some((_, last)) => t!(
+            lang,
+            "owner.reloaded",
+            dir = data_dir().display(),
+            until = prefs.format_time(last.expiry)
+        ),
+        None => t!(lang, "owner.reloaded_empty", dir = data_dir().display()),
+    };
+
+    ctx.send(
+        CreateReply::default().ephemeral(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "owner.reload_title"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
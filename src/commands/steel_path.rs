@@ -0,0 +1,103 @@
+use chrono::{Days, Utc};
+use poise::{
+    CreateReply,
+    command,
+    serenity_prelude::{AutocompleteChoice, CreateEmbed},
+};
+use warframe::worldstate::{TimedEvent, queryable::SteelPath};
+
+use crate::{CmdRet, Context, i18n::t, settings::Preferences, utils::ContextExt};
+
+/// Autocompletes the rewards of Teshin's honors rotation.
+pub async fn autocomplete_honors_reward(
+    ctx: Context<'_>,
+    partial: &str,
+) -> Vec<AutocompleteChoice> {
+    let Ok(steel_path) = ctx.data().worldstate().fetch::<SteelPath>().await else {
+        return Vec::new();
+    };
+    let partial = partial.to_lowercase();
+
+    steel_path
+        .rotation
+        .iter()
+        .map(|item| item.name.as_str())
+        .filter(|name| name.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|name| AutocompleteChoice::new(name, name))
+        .collect()
+}
+
+fn create_steel_path_embed(prefs: &Preferences, steel_path: &SteelPath) -> CreateEmbed {
+    let lang = prefs.language;
+
+    // incursions are rerolled at midnight UTC
+    let incursion_reset = Utc::now()
+        .date_naive()
+        .checked_add_days(Days::new(1))
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| time.and_utc())
+        .unwrap_or_else(Utc::now);
+
+    let rotation = steel_path
+        .rotation
+        .iter()
+        .map(|item| {
+            if item.name == steel_path.current_reward.name {
+                format!("- **{}** ({})", item.name, item.cost)
+            } else {
+                format!("- {} ({})", item.name, item.cost)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    prefs
+        .embed()
+        .title(t!(lang, "steel_path.title"))
+        .field(
+            t!(lang, "steel_path.honors_reward"),
+            t!(
+                lang,
+                "steel_path.reward_cost",
+                reward = steel_path.current_reward.name,
+                cost = steel_path.current_reward.cost
+            ),
+            false,
+        )
+        .field(
+            t!(lang, "steel_path.rotation_changes"),
+            prefs.format_time(steel_path.expiry()),
+            false,
+        )
+        .field(t!(lang, "steel_path.rotation"), rotation, false)
+        .field(
+            t!(lang, "steel_path.incursions"),
+            t!(
+                lang,
+                "steel_path.incursions_description",
+                time = prefs.format_time(incursion_reset)
+            ),
+            false,
+        )
+}
+
+/// Shows Teshin's current honors reward and when the daily Steel Path incursions reset
+#[command(slash_command, rename = "steel-path")]
+pub async fn steel_path(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let steel_path = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<SteelPath>(prefs.language.into())
+        .await?;
+
+    ctx.send(
+        CreateReply::default()
+            .embed(create_steel_path_embed(&prefs, &steel_path))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
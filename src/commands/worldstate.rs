@@ -1,26 +1,14 @@
 #![allow(unstable_name_collisions)]
-use std::{fmt::Display, time::Duration};
+use std::fmt::Display;
 
 use chrono::{DateTime, Utc};
-use futures::StreamExt;
 use itertools::Itertools;
 use poise::{
     CreateReply,
     command,
-    serenity_prelude::{
-        ButtonStyle,
-        ComponentInteractionCollector,
-        CreateActionRow,
-        CreateButton,
-        CreateEmbed,
-        CreateInteractionResponse,
-        CreateInteractionResponseMessage,
-        FormattedTimestamp,
-        FormattedTimestampStyle,
-        Timestamp,
-    },
+    serenity_prelude::{ButtonStyle, CreateActionRow, CreateEmbed},
 };
-use poise_paginator::{CancellationType, paginate};
+use serde::{Deserialize, Serialize};
 use warframe::worldstate::{
     Opposite,
     SyndicateMission,
@@ -28,7 +16,16 @@ use warframe::worldstate::{
     queryable::{CambionDrift, Cetus, OrbVallis, Syndicate},
 };
 
-use crate::{CmdRet, Context, Error, utils::embed};
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    components::{ComponentState, Page, open_button},
+    i18n::{self, Language, t},
+    settings::Preferences,
+    utils::ContextExt,
+};
 
 #[command(
     slash_command,
@@ -42,17 +39,20 @@ pub async fn worldstate(_: Context<'_>) -> CmdRet {
 /// Retrieves the current state of Cetus
 #[command(slash_command)]
 pub async fn cetus(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let wf = ctx.data().worldstate();
-    let worldstate_item = wf.fetch::<Cetus>().await?;
+    let worldstate_item = wf.fetch_using_lang::<Cetus>(lang.into()).await?;
 
     let embed = create_worldstate_embed(
-        "Cetus",
+        &prefs,
+        t!(lang, "worldstate.cetus"),
         worldstate_item.state,
         worldstate_item.expiry(),
         "https://wiki.warframe.com/images/thumb/Plains_of_Eidolon.png/300px-Plains_of_Eidolon.png?c7c8c",
     )?;
 
-    send_worldstate(ctx, embed, Syndicate::Ostrons, "Ostron Bounties").await?;
+    send_worldstate(ctx, prefs, embed, OpenWorld::Cetus).await?;
 
     Ok(())
 }
@@ -60,23 +60,20 @@ pub async fn cetus(ctx: Context<'_>) -> CmdRet {
 /// Retrieves the current state of the Orb Vallis
 #[command(slash_command, rename = "orb-vallis")]
 pub async fn orb_vallis(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let wf = ctx.data().worldstate();
-    let worldstate_item = wf.fetch::<OrbVallis>().await?;
+    let worldstate_item = wf.fetch_using_lang::<OrbVallis>(lang.into()).await?;
 
     let embed = create_worldstate_embed(
-        "Orb Vallis",
+        &prefs,
+        t!(lang, "worldstate.orb_vallis"),
         worldstate_item.state,
         worldstate_item.expiry(),
         "https://wiki.warframe.com/images/thumb/Orb_Vallis.png/300px-Orb_Vallis.png?7f8e7",
     )?;
 
-    send_worldstate(
-        ctx,
-        embed,
-        Syndicate::SolarisUnited,
-        "Solaris United Bounties",
-    )
-    .await?;
+    send_worldstate(ctx, prefs, embed, OpenWorld::OrbVallis).await?;
 
     Ok(())
 }
@@ -84,137 +81,147 @@ pub async fn orb_vallis(ctx: Context<'_>) -> CmdRet {
 /// Retrieves the current state of the Cambion Drift
 #[command(slash_command, rename = "cambion-drift")]
 pub async fn cambion_drift(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let wf = ctx.data().worldstate();
-    let worldstate_item = wf.fetch::<CambionDrift>().await?;
+    let worldstate_item = wf.fetch_using_lang::<CambionDrift>(lang.into()).await?;
 
     let embed = create_worldstate_embed(
-        "Cambion Drift",
+        &prefs,
+        t!(lang, "worldstate.cambion_drift"),
         worldstate_item.state,
         worldstate_item.expiry(),
         "https://wiki.warframe.com/images/thumb/CambionDrift.jpg/300px-CambionDrift.jpg?f2516",
     )?;
 
-    send_worldstate(ctx, embed, Syndicate::Entrati, "Entrati Bounties").await?;
+    send_worldstate(ctx, prefs, embed, OpenWorld::CambionDrift).await?;
 
     Ok(())
 }
 
-async fn send_worldstate(
-    ctx: Context<'_>,
-    worldstate_embed: CreateEmbed,
-    syndicate: Syndicate,
-    bounty_title: &'static str,
-) -> Result<(), Error> {
-    let id = format!("{}_bounty", ctx.id());
-
-    let bounty_button = CreateButton::new(&id)
-        .label("Bounties")
-        .style(ButtonStyle::Primary);
+/// An open world with bounties, as stored behind the Bounties button of its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OpenWorld {
+    Cetus,
+    OrbVallis,
+    CambionDrift,
+}
 
-    let msg = ctx
-        .send(
-            CreateReply::default()
-                .embed(worldstate_embed.clone())
-                .components(vec![CreateActionRow::Buttons(vec![bounty_button])]),
-        )
-        .await?;
+impl OpenWorld {
+    fn syndicate(self) -> Syndicate {
+        match self {
+            Self::Cetus => Syndicate::Ostrons,
+            Self::OrbVallis => Syndicate::SolarisUnited,
+            Self::CambionDrift => Syndicate::Entrati,
+        }
+    }
 
-    let mut collector = ComponentInteractionCollector::new(ctx)
-        .author_id(ctx.author().id)
-        .channel_id(ctx.channel_id())
-        .timeout(Duration::from_secs(60))
-        .filter(move |i| i.data.custom_id == id)
-        .stream();
+    fn bounty_title(self, lang: Language) -> &'static str {
+        match self {
+            Self::Cetus => t!(lang, "worldstate.bounties.ostrons"),
+            Self::OrbVallis => t!(lang, "worldstate.bounties.solaris_united"),
+            Self::CambionDrift => t!(lang, "worldstate.bounties.entrati"),
+        }
+    }
 
-    if let Some(press) = collector.next().await {
-        let mission = ctx
-            .data()
+    /// The bounty at `index` of the current rotation, or the last one if the rotation has fewer.
+    pub async fn bounty_page(
+        self,
+        data: &AppData,
+        prefs: &Preferences,
+        index: usize,
+    ) -> Result<Page, Error> {
+        let lang = prefs.language;
+        let mission = data
             .worldstate()
-            .fetch::<SyndicateMission>()
+            .fetch_using_lang::<SyndicateMission>(lang.into())
             .await?
             .into_iter()
-            .find(|bounty: &SyndicateMission| bounty.syndicate_key == syndicate)
-            .ok_or("Not found")?;
-
-        let state = BountyState {
-            mission: &mission,
-            title: bounty_title,
-        };
-
-        press
-            .create_response(
-                ctx,
-                CreateInteractionResponse::UpdateMessage(
-                    CreateInteractionResponseMessage::new().components(Vec::new()),
-                ),
-            )
-            .await?;
-
-        paginate(
-            ctx,
-            generate_bounty_embed,
-            mission.jobs.len(),
-            Duration::from_secs(180),
-            state,
-        )
-        .await?;
-    } else {
-        let disabled_bounty_button = CreateButton::new("_") // empty string cause it won't be clickable anyway
-            .label("Bounties")
-            .style(ButtonStyle::Primary)
-            .disabled(true);
-
-        msg.edit(
-            ctx.into(),
-            CreateReply::default()
-                .embed(worldstate_embed)
-                .components(vec![CreateActionRow::Buttons(vec![disabled_bounty_button])]),
-        )
-        .await?;
+            .find(|bounty: &SyndicateMission| bounty.syndicate_key == self.syndicate())
+            .filter(|bounty| !bounty.jobs.is_empty())
+            .ok_or(t!(lang, "worldstate.bounties_not_found"))?;
+
+        let index = index.min(mission.jobs.len() - 1);
+        let embed = generate_bounty_embed(prefs, &mission, self.bounty_title(lang), index);
+
+        Ok(Page::new(embed, index, mission.jobs.len()))
     }
+}
+
+async fn send_worldstate(
+    ctx: Context<'_>,
+    prefs: Preferences,
+    worldstate_embed: CreateEmbed,
+    region: OpenWorld,
+) -> Result<(), Error> {
+    let lang = prefs.language;
+    let state_id = ComponentState::Bounties(region)
+        .save(ctx.data(), ctx.author().id.get() as i64)
+        .await?;
+
+    let bounty_button = open_button(state_id)
+        .label(t!(lang, "worldstate.bounties"))
+        .style(ButtonStyle::Primary);
+
+    ctx.send(
+        CreateReply::default()
+            .embed(worldstate_embed)
+            .components(vec![CreateActionRow::Buttons(vec![bounty_button])])
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
 
     Ok(())
 }
 
-#[derive(Debug, Clone, Copy)]
-struct BountyState<'a> {
-    mission: &'a SyndicateMission,
-    title: &'static str,
+/// Translates a region state such as `Night` or `Fass` through the catalogue.
+fn state_name<S: Display>(lang: Language, state: S) -> String {
+    i18n::translate(
+        lang,
+        &format!("worldstate.state.{}", state.to_string().to_lowercase()),
+    )
+    .to_owned()
 }
 
 fn create_worldstate_embed<S: Display + Opposite>(
+    prefs: &Preferences,
     title: &str,
     region_state: S,
     expiry: DateTime<Utc>,
     thumbnail: &str,
 ) -> Result<CreateEmbed, Error> {
-    Ok(embed()
+    let lang = prefs.language;
+
+    Ok(prefs
+        .embed()
         .title(title)
-        .field("State", region_state.to_string(), false)
         .field(
-            region_state.opposite().to_string(),
-            FormattedTimestamp::new(
-                Timestamp::from_unix_timestamp(expiry.timestamp())?,
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "worldstate.state"),
+            state_name(lang, &region_state),
+            false,
+        )
+        .field(
+            state_name(lang, region_state.opposite()),
+            prefs.format_time(expiry),
             false,
         )
         .thumbnail(thumbnail))
 }
 
-async fn generate_bounty_embed(
-    _ctx: Context<'_>,
+fn generate_bounty_embed(
+    prefs: &Preferences,
+    mission: &SyndicateMission,
+    title: &str,
     idx: usize,
-    _cancellation_type: CancellationType,
-    state: BountyState<'_>,
-) -> Result<CreateEmbed, Error> {
-    let job = &state.mission.jobs[idx];
+) -> CreateEmbed {
+    let job = &mission.jobs[idx];
+    let lang = prefs.language;
 
-    let embed = embed()
-        .title(state.title)
+    prefs
+        .embed()
+        .title(title)
         .field(
-            "Reward Pool",
+            t!(lang, "worldstate.reward_pool"),
             job.reward_pool
                 .iter()
                 .map(|reward| format!("- {reward}"))
@@ -222,16 +229,12 @@ async fn generate_bounty_embed(
             false,
         )
         .field(
-            "Ends",
-            FormattedTimestamp::new(
-                Timestamp::from_unix_timestamp(state.mission.expiry().timestamp())?,
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "common.ends"),
+            prefs.format_time(mission.expiry()),
             false,
         )
         .field(
-            "Standing per Stage",
+            t!(lang, "worldstate.standing_per_stage"),
             job.standing_stages
                 .iter()
                 .enumerate()
@@ -241,14 +244,12 @@ async fn generate_bounty_embed(
             true,
         )
         .field(
-            "Enemy Levels",
+            t!(lang, "worldstate.enemy_levels"),
             job.enemy_levels
                 .iter()
                 .map(|num| num.to_string())
                 .intersperse(" - ".to_owned())
                 .collect::<String>(),
             true,
-        );
-
-    Ok(embed)
+        )
 }
@@ -0,0 +1,86 @@
+use std::future::Future;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+use super::STATE_RETENTION;
+
+/// A row of `component_states`, with the state still serialized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredComponentState {
+    pub user_id: i64,
+    pub state: String,
+}
+
+pub trait ComponentStateDbExtension {
+    /// Stores `state` (a serialized [`ComponentState`](super::ComponentState)) and returns its id.
+    ///
+    /// States older than [`STATE_RETENTION`] are purged on the way.
+    fn save_component_state(
+        &self,
+        user_id: i64,
+        state: &str,
+    ) -> impl Future<Output = Result<i64, sqlx::Error>> + Send;
+
+    /// The state with `id`, unless it is older than [`STATE_RETENTION`].
+    fn get_component_state(
+        &self,
+        id: i64,
+    ) -> impl Future<Output = Result<Option<StoredComponentState>, sqlx::Error>> + Send;
+}
+
+impl ComponentStateDbExtension for SqlitePool {
+    async fn save_component_state(&self, user_id: i64, state: &str) -> Result<i64, sqlx::Error> {
+        let now = Utc::now();
+        let oldest = now - STATE_RETENTION;
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM component_states
+            WHERE created_at < $1;
+            ",
+            oldest
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        // bound instead of defaulted, so it has the same format as the bounds it is compared to
+        let id = sqlx::query!(
+            "
+            INSERT INTO component_states (user_id, state, created_at)
+            VALUES ($1, $2, $3);
+            ",
+            user_id,
+            state,
+            now
+        )
+        .execute(tx.as_mut())
+        .await?
+        .last_insert_rowid();
+
+        tx.commit().await?;
+
+        Ok(id)
+    }
+
+    async fn get_component_state(
+        &self,
+        id: i64,
+    ) -> Result<Option<StoredComponentState>, sqlx::Error> {
+        let oldest = Utc::now() - STATE_RETENTION;
+
+        sqlx::query_as!(
+            StoredComponentState,
+            "
+            SELECT user_id, state
+            FROM component_states
+            WHERE id = $1 AND created_at >= $2
+            ",
+            id,
+            oldest
+        )
+        .fetch_optional(self)
+        .await
+    }
+}
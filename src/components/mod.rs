@@ -0,0 +1,351 @@
+pub mod db;
+
+use chrono::TimeDelta;
+use poise::{
+    CreateReply,
+    serenity_prelude::{
+        self,
+        ButtonStyle,
+        ComponentInteraction,
+        CreateActionRow,
+        CreateButton,
+        CreateEmbed,
+        CreateInteractionResponse,
+        CreateInteractionResponseMessage,
+        colours::roles::DARK_RED,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    AppData,
+    CmdRet,
+    Context,
+    Error,
+    commands::{
+        arbi::UpcomingArbitrations,
+        event::events_page,
+        fissures::FissureQuery,
+        market::orders::OrderQuery,
+        nightwave::nightwave_page,
+        node::node_embed,
+        worldstate::OpenWorld,
+    },
+    components::db::{ComponentStateDbExtension, StoredComponentState},
+    i18n::t,
+    lfg,
+    settings::Preferences,
+};
+
+/// How long a [`ComponentState`] is kept. Presses after that get a message telling the user
+/// to run the command again.
+///
+/// Without a limit, every paged reply would grow the table forever, and the live data these
+/// buttons show is long outdated after a month anyway.
+pub const STATE_RETENTION: TimeDelta = TimeDelta::days(30);
+
+/// The prefix of the `custom_id` of every button backed by a [`ComponentState`].
+const STATE_PREFIX: &str = "state:";
+
+/// What a message's buttons refer to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", content = "state", rename_all = "snake_case")]
+pub enum ComponentState {
+    /// The bounties of an open world, opened from its `/worldstate` message.
+    Bounties(OpenWorld),
+    /// The star chart node of an `/upcoming-arbitration`.
+    NodeDetails {
+        node_id: String,
+    },
+    UpcomingArbitrations(UpcomingArbitrations),
+    MarketOrders(OrderQuery),
+    Fissures(FissureQuery),
+    /// The running events, which are fetched again for every page.
+    Events,
+    Nightwave,
+}
+
+/// One page of a paged message.
+pub struct Page {
+    pub embed: CreateEmbed,
+    pub index: usize,
+    pub count: usize,
+}
+
+impl Page {
+    /// The page at `index` out of `count`, which the caller may have to clamp `index` for.
+    pub fn new(embed: CreateEmbed, index: usize, count: usize) -> Self {
+        Self {
+            embed,
+            index,
+            count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Action {
+    Open,
+    Page(usize),
+}
+
+impl Action {
+    fn custom_id(self, state_id: i64) -> String {
+        match self {
+            Self::Open => format!("{STATE_PREFIX}{state_id}:open"),
+            Self::Page(index) => format!("{STATE_PREFIX}{state_id}:page:{index}"),
+        }
+    }
+
+    /// The state id and action encoded in `custom_id` by [`Action::custom_id`].
+    fn parse(custom_id: &str) -> Option<(i64, Self)> {
+        let (state_id, action) = custom_id.strip_prefix(STATE_PREFIX)?.split_once(':')?;
+
+        let action = match action.split_once(':') {
+            None if action == "open" => Self::Open,
+            Some(("page", index)) => Self::Page(index.parse().ok()?),
+            _ => return None,
+        };
+
+        Some((state_id.parse().ok()?, action))
+    }
+}
+
+impl ComponentState {
+    /// The owner and state of a stored row, or `None` if it expired or can no longer be read
+    /// (e.g. after its format changed).
+    fn decode(stored: Option<StoredComponentState>) -> Option<(i64, Self)> {
+        let stored = stored?;
+        let state = serde_json::from_str(&stored.state).ok()?;
+
+        Some((stored.user_id, state))
+    }
+
+    /// Stores the state for the buttons of a message sent to `user_id` and returns its id.
+    pub async fn save(&self, data: &AppData, user_id: i64) -> Result<i64, Error> {
+        let state = serde_json::to_string(self)?;
+
+        Ok(data.db().save_component_state(user_id, &state).await?)
+    }
+
+    /// The page at `index`. [`ComponentState::NodeDetails`] has none, as it is only ever opened.
+    async fn page(&self, data: &AppData, prefs: &Preferences, index: usize) -> Result<Page, Error> {
+        match self {
+            Self::Bounties(region) => region.bounty_page(data, prefs, index).await,
+            Self::UpcomingArbitrations(upcoming) => upcoming.page(data, prefs, index),
+            Self::MarketOrders(query) => query.page(data, prefs, index).await,
+            Self::Fissures(query) => query.page(data, prefs, index).await,
+            Self::Events => events_page(data, prefs, index).await,
+            Self::Nightwave => nightwave_page(data, prefs, index).await,
+            Self::NodeDetails { .. } => Err(t!(prefs.language, "components.no_pages").into()),
+        }
+    }
+}
+
+/// A button that performs its action once, e.g. to show more details.
+pub fn open_button(state_id: i64) -> CreateButton {
+    CreateButton::new(Action::Open.custom_id(state_id))
+}
+
+/// Previous/next buttons for `page`, or nothing if there is only one page.
+pub fn page_buttons(state_id: i64, page: &Page) -> Vec<CreateActionRow> {
+    if page.count <= 1 {
+        return Vec::new();
+    }
+
+    // the counter is never clickable, but its id still has to be unique within the message
+    let counter = CreateButton::new(format!("{STATE_PREFIX}{state_id}:counter"))
+        .label(format!("{}/{}", page.index + 1, page.count))
+        .style(ButtonStyle::Secondary)
+        .disabled(true);
+
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(Action::Page(page.index.saturating_sub(1)).custom_id(state_id))
+            .emoji('◀')
+            .disabled(page.index == 0),
+        counter,
+        CreateButton::new(Action::Page(page.index + 1).custom_id(state_id))
+            .emoji('▶')
+            .disabled(page.index + 1 >= page.count),
+    ])]
+}
+
+/// Stores `state` and replies with its first page.
+pub async fn send_pages(ctx: Context<'_>, prefs: &Preferences, state: ComponentState) -> CmdRet {
+    let page = state.page(ctx.data(), prefs, 0).await?;
+    let state_id = state.save(ctx.data(), ctx.author().id.get() as i64).await?;
+
+    ctx.send(
+        CreateReply::default()
+            .components(page_buttons(state_id, &page))
+            .embed(page.embed)
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn ephemeral(content: impl Into<String>) -> CreateInteractionResponse {
+    CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    )
+}
+
+/// Handles a button press.
+///
+/// These buttons carry everything needed to handle them in their `custom_id`, or the id of a
+/// stored [`ComponentState`], so they keep working without a collector and across restarts.
+/// Presses on buttons this router does not know are ignored.
+pub async fn route(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    press: &ComponentInteraction,
+) -> Result<(), Error> {
+    let custom_id = press.data.custom_id.as_str();
+
+    if custom_id.starts_with(lfg::COMPONENT_PREFIX) {
+        return lfg::handle_component(ctx, data, press).await;
+    }
+
+    let Some((state_id, action)) = Action::parse(custom_id) else {
+        return Ok(());
+    };
+
+    let user_id = press.user.id.get() as i64;
+    let prefs = Preferences::resolve(
+        data.db(),
+        user_id,
+        press.guild_id.map(|id| id.get() as i64),
+        Some(&press.locale),
+    )
+    .await?;
+    let lang = prefs.language;
+
+    let stored = data.db().get_component_state(state_id).await?;
+
+    let Some((owner_id, state)) = ComponentState::decode(stored) else {
+        press
+            .create_response(ctx, ephemeral(t!(lang, "components.expired")))
+            .await?;
+        return Ok(());
+    };
+
+    if owner_id != user_id {
+        press
+            .create_response(ctx, ephemeral(t!(lang, "components.not_yours")))
+            .await?;
+        return Ok(());
+    }
+
+    if let Err(e) = respond(ctx, data, &prefs, press, state_id, &state, action).await {
+        tracing::warn!(error = %e, "Error in component interaction");
+
+        press
+            .create_response(
+                ctx,
+                CreateInteractionResponse::Message(
+                    CreateInteractionResponseMessage::new()
+                        .embed(
+                            CreateEmbed::default()
+                                .title(t!(lang, "common.error"))
+                                .description(e.to_string())
+                                .color(DARK_RED),
+                        )
+                        .ephemeral(true),
+                ),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+async fn respond(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    prefs: &Preferences,
+    press: &ComponentInteraction,
+    state_id: i64,
+    state: &ComponentState,
+    action: Action,
+) -> Result<(), Error> {
+    let response = match (state, action) {
+        (ComponentState::NodeDetails { node_id }, _) => {
+            let game_data = data.game_data();
+            let node = node_embed(prefs, &game_data, node_id)
+                .ok_or_else(|| t!(prefs.language, "components.expired"))?;
+
+            // the node is shown below the embeds the message already has
+            let mut embeds = press
+                .message
+                .embeds
+                .iter()
+                .cloned()
+                .map(CreateEmbed::from)
+                .collect::<Vec<_>>();
+            embeds.push(node);
+
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .embeds(embeds)
+                    .components(Vec::new()),
+            )
+        },
+        // the pages are sent as a new message, leaving the one with the button as it is
+        (state, Action::Open) => {
+            let page = state.page(data, prefs, 0).await?;
+
+            CreateInteractionResponse::Message(
+                CreateInteractionResponseMessage::new()
+                    .components(page_buttons(state_id, &page))
+                    .embed(page.embed)
+                    .ephemeral(prefs.ephemeral),
+            )
+        },
+        (state, Action::Page(index)) => {
+            let page = state.page(data, prefs, index).await?;
+
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new()
+                    .components(page_buttons(state_id, &page))
+                    .embed(page.embed),
+            )
+        },
+    };
+
+    press.create_response(ctx, response).await?;
+
+    Ok(())
+}
+
+#[test]
+fn states_are_routed() {
+    for action in [Action::Open, Action::Page(0), Action::Page(12)] {
+        assert_eq!(Action::parse(&action.custom_id(42)), Some((42, action)));
+    }
+
+    assert_eq!(Action::parse("state:42:counter"), None);
+    assert_eq!(Action::parse("state:42:page:-1"), None);
+    assert_eq!(Action::parse("state:x:open"), None);
+    assert_eq!(Action::parse("lfg:join:42"), None);
+
+    let state = ComponentState::NodeDetails {
+        node_id: "SolNode1".to_owned(),
+    };
+    let stored = |state: String| Some(StoredComponentState { user_id: 7, state });
+
+    assert_eq!(
+        ComponentState::decode(stored(serde_json::to_string(&state).unwrap())),
+        Some((7, state))
+    );
+
+    // expired, and written by an older version that had a different format
+    assert_eq!(ComponentState::decode(None), None);
+    assert_eq!(
+        ComponentState::decode(stored(r#"{"kind":"removed_kind"}"#.to_owned())),
+        None
+    );
+}
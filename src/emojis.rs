@@ -1,2 +1,11 @@
 pub const PLATINUM: &str = "<:platinum:1405547068704952351>";
 pub const CREDITS: &str = "<:credits:1406660650318696620>";
+
+pub const SHARD_AMBER: &str = "<:shard_amber:1299400892357017610>";
+pub const SHARD_CRIMSON: &str = "<:shard_crimson:1299400724052054098>";
+pub const SHARD_AZURE: &str = "<:shard_azure:1299400931825418271>";
+
+// Used in servers that turned custom emojis off, as the ones above only exist in the bot's
+// own servers
+pub const PLATINUM_PLAIN: &str = "p";
+pub const CREDITS_PLAIN: &str = "cr";
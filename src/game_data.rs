@@ -0,0 +1,183 @@
+use std::{
+    borrow::Cow,
+    collections::HashMap,
+    env,
+    path::{Path, PathBuf},
+};
+
+use arbitration_data::{
+    ArbitrationData,
+    model::{dict::LanguageDict, mapped::Tier, regions::ExportRegions},
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, de::IntoDeserializer};
+use strum::IntoEnumIterator;
+
+use crate::{
+    Error,
+    arbitration::{Regions, RegionsExport},
+    i18n::Language,
+};
+
+const EMBEDDED_SCHEDULE: &str = include_str!("../arbys.csv");
+const EMBEDDED_REGIONS: &str = include_str!("../regions.json");
+const EMBEDDED_DICT: &str = include_str!("../dict.en.json");
+
+/// The directory `arbys.csv`, `regions.json` and the `dict.<lang>.json` files are read from.
+///
+/// Set with `DATA_DIR`, defaults to the working directory.
+pub fn data_dir() -> PathBuf {
+    env::var("DATA_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."))
+}
+
+/// Everything derived from the arbitration schedule and the game's data exports.
+///
+/// Loaded as a whole, so that a reload never mixes files of different versions.
+pub struct GameData {
+    arbi_data: HashMap<Language, ArbitrationData>,
+    regions: HashMap<Language, Regions>,
+    /// The arbitration tier of every node in the schedule, by node id.
+    node_tiers: HashMap<String, Tier>,
+}
+
+impl GameData {
+    /// Loads the data files from `dir`, using the embedded copies of `arbys.csv`,
+    /// `regions.json` and `dict.en.json` where a file is missing.
+    pub fn load(dir: &Path) -> Result<Self, Error> {
+        let schedule = read_or(dir, "arbys.csv", EMBEDDED_SCHEDULE)?;
+        let regions_export = read_or(dir, "regions.json", EMBEDDED_REGIONS)?;
+        let unresolved_regions: RegionsExport = serde_json::from_str(&regions_export)?;
+
+        let mut arbi_data = HashMap::new();
+        let mut regions = HashMap::new();
+
+        for lang in Language::iter() {
+            let dict = match lang {
+                Language::En => read_or(dir, "dict.en.json", EMBEDDED_DICT)?,
+                // Other languages are optional, as their dictionaries are too large to embed
+                lang => match read(dir, &format!("dict.{}.json", lang.code()))? {
+                    Some(dict) => Cow::Owned(dict),
+                    None => {
+                        tracing::info!(
+                            language = lang.code(),
+                            "No language dict found, using English"
+                        );
+                        continue;
+                    },
+                },
+            };
+
+            // the dicts are by far the largest files, so each is only parsed once
+            let names: HashMap<String, String> = serde_json::from_str(&dict)?;
+            regions.insert(lang, Regions::new(&unresolved_regions, &names, &schedule));
+
+            // consumed by `ArbitrationData::new`, which is why it is parsed for every language
+            let export_regions: ExportRegions<'_> = serde_json::from_str(&regions_export)?;
+            let language_dict = LanguageDict::deserialize(
+                IntoDeserializer::<'_, serde_json::Error>::into_deserializer(names),
+            )?;
+            arbi_data.insert(
+                lang,
+                ArbitrationData::new(
+                    csv::Reader::from_reader(schedule.as_bytes()),
+                    export_regions,
+                    language_dict,
+                )?,
+            );
+        }
+
+        let node_tiers = node_tiers(&arbi_data[&Language::En], &regions[&Language::En]);
+
+        Ok(Self {
+            arbi_data,
+            regions,
+            node_tiers,
+        })
+    }
+
+    pub fn arbi_data(&self) -> &ArbitrationData {
+        self.localized_arbi_data(Language::En)
+    }
+
+    /// The arbitration data with node names in `lang`, falling back to English if no
+    /// dictionary was loaded for it.
+    pub fn localized_arbi_data(&self, lang: Language) -> &ArbitrationData {
+        self.arbi_data
+            .get(&lang)
+            .or_else(|| self.arbi_data.get(&Language::En))
+            .expect("english arbitration data is always loaded")
+    }
+
+    /// When the last arbitration in the schedule ends, or `None` if none are left.
+    pub fn schedule_end(&self) -> Option<DateTime<Utc>> {
+        self.arbi_data()
+            .iter_upcoming()
+            .last()
+            .map(|(_, arbi)| arbi.expiry)
+    }
+
+    /// Whether the schedule still contains upcoming arbitrations.
+    pub fn has_upcoming_arbitrations(&self) -> bool {
+        self.arbi_data().iter_upcoming().next().is_some()
+    }
+
+    /// The arbitration tier of the node `id`, or `None` if it is not in the schedule.
+    pub fn node_tier(&self, id: &str) -> Option<&Tier> {
+        self.node_tiers.get(id)
+    }
+
+    pub fn regions(&self) -> &Regions {
+        self.localized_regions(Language::En)
+    }
+
+    /// The star chart nodes with names in `lang`, falling back to English like
+    /// [`GameData::localized_arbi_data`].
+    pub fn localized_regions(&self, lang: Language) -> &Regions {
+        self.regions
+            .get(&lang)
+            .or_else(|| self.regions.get(&Language::En))
+            .expect("english regions are always loaded")
+    }
+}
+
+/// Maps every node in `arbi_data` to its tier, matching nodes by their English name and planet
+/// as the mapped arbitrations no longer carry their ids.
+///
+/// Collected once while loading, so that a node keeps its tier after its last occurrence passed.
+fn node_tiers(arbi_data: &ArbitrationData, english: &Regions) -> HashMap<String, Tier> {
+    let ids = english
+        .nodes()
+        .map(|node| ((node.name.as_str(), node.planet.as_str()), node.id.as_str()))
+        .collect::<HashMap<_, _>>();
+
+    let mut tiers = HashMap::new();
+
+    for (_, arbi) in arbi_data.iter_upcoming() {
+        if let Some(id) = ids.get(&(arbi.node.as_str(), arbi.planet.as_str())) {
+            tiers
+                .entry((*id).to_owned())
+                .or_insert_with(|| arbi.tier.clone());
+        }
+    }
+
+    tiers
+}
+
+fn read(dir: &Path, file: &str) -> Result<Option<String>, Error> {
+    let path = dir.join(file);
+
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    tracing::info!(path = %path.display(), "Loading data file");
+    Ok(Some(std::fs::read_to_string(path)?))
+}
+
+fn read_or(dir: &Path, file: &str, embedded: &'static str) -> Result<Cow<'static, str>, Error> {
+    Ok(read(dir, file)?
+        .map(Cow::Owned)
+        .unwrap_or(Cow::Borrowed(embedded)))
+}
@@ -0,0 +1,157 @@
+use std::{collections::HashMap, fmt::Display, sync::LazyLock};
+
+use poise::ChoiceParameter;
+use strum::IntoEnumIterator;
+use warframe::{market, worldstate};
+
+/// The languages the bot can answer in.
+///
+/// Stored as its lowercase code (`en`, `de`, `fr`) in the database.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    Hash,
+    sqlx::Type,
+    poise::ChoiceParameter,
+    strum::EnumIter,
+)]
+#[sqlx(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    #[name = "English"]
+    En,
+
+    #[name = "Deutsch"]
+    De,
+
+    #[name = "Français"]
+    Fr,
+}
+
+impl Language {
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::De => "de",
+            Language::Fr => "fr",
+        }
+    }
+
+    /// Maps a Discord locale (e.g. `en-US`, `de`, `fr`) to a supported language.
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale.split('-').next()?;
+        Language::iter().find(|lang| lang.code() == code)
+    }
+
+    fn catalogue_source(self) -> &'static str {
+        match self {
+            Language::En => include_str!("../locales/en.json"),
+            Language::De => include_str!("../locales/de.json"),
+            Language::Fr => include_str!("../locales/fr.json"),
+        }
+    }
+}
+
+impl From<Language> for market::Language {
+    fn from(value: Language) -> Self {
+        match value {
+            Language::En => market::Language::En,
+            Language::De => market::Language::De,
+            Language::Fr => market::Language::Fr,
+        }
+    }
+}
+
+impl From<Language> for worldstate::Language {
+    fn from(value: Language) -> Self {
+        match value {
+            Language::En => worldstate::Language::EN,
+            Language::De => worldstate::Language::DE,
+            Language::Fr => worldstate::Language::FR,
+        }
+    }
+}
+
+static CATALOGUES: LazyLock<HashMap<Language, HashMap<String, String>>> = LazyLock::new(|| {
+    Language::iter()
+        .map(|lang| {
+            let catalogue = serde_json::from_str(lang.catalogue_source())
+                .unwrap_or_else(|e| panic!("invalid catalogue for `{}`: {e}", lang.code()));
+            (lang, catalogue)
+        })
+        .collect()
+});
+
+/// Looks up `key` in the catalogue of `lang`, falling back to English and then to the key
+/// itself.
+pub fn translate<'a>(lang: Language, key: &'a str) -> &'a str {
+    let lookup = |lang: Language| CATALOGUES.get(&lang)?.get(key).map(String::as_str);
+
+    lookup(lang)
+        .or_else(|| lookup(Language::En))
+        .unwrap_or_else(|| {
+            tracing::warn!(key, "Missing catalogue entry");
+            key
+        })
+}
+
+/// Like [`translate`], but replaces every `{name}` placeholder with its value.
+pub fn translate_with(lang: Language, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    args.iter()
+        .fold(translate(lang, key).to_owned(), |text, (name, value)| {
+            text.replace(&format!("{{{name}}}"), &value.to_string())
+        })
+}
+
+/// Translates a catalogue key, optionally filling in named placeholders.
+///
+/// ```ignore
+/// t!(lang, "arbi.none_upcoming");
+/// t!(lang, "arbi.none_upcoming_tier", tier = tier);
+/// ```
+macro_rules! t {
+    ($lang:expr, $key:literal $(,)?) => {
+        $crate::i18n::translate($lang, $key)
+    };
+    ($lang:expr, $key:literal, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::translate_with(
+            $lang,
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),+],
+        )
+    };
+}
+
+pub(crate) use t;
+
+/// Gives access to the `name_localized` names of a [`ChoiceParameter`].
+pub trait LocalizedChoice: ChoiceParameter {
+    fn name_in(&self, lang: Language) -> &'static str {
+        self.localized_name(lang.code())
+            .unwrap_or_else(|| self.name())
+    }
+}
+
+impl<T: ChoiceParameter> LocalizedChoice for T {}
+
+#[test]
+fn catalogues_are_complete() {
+    let english = &CATALOGUES[&Language::En];
+
+    for lang in Language::iter() {
+        let missing = english
+            .keys()
+            .filter(|key| !CATALOGUES[&lang].contains_key(*key))
+            .collect::<Vec<_>>();
+
+        assert!(
+            missing.is_empty(),
+            "`{}` is missing {missing:?}",
+            lang.code()
+        );
+    }
+}
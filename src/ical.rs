@@ -0,0 +1,121 @@
+use chrono::{DateTime, Utc};
+
+/// The maximum length of a content line in octets, excluding the line break (RFC 5545, 3.1).
+const MAX_LINE_LENGTH: usize = 75;
+
+/// A single `VEVENT` of a [`Calendar`].
+#[derive(Debug, Clone)]
+pub struct Event {
+    pub uid: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub start: DateTime<Utc>,
+    pub end: DateTime<Utc>,
+}
+
+/// A minimal iCalendar (`.ics`) writer.
+#[derive(Debug, Clone)]
+pub struct Calendar {
+    name: String,
+    events: Vec<Event>,
+}
+
+impl Calendar {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            events: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, event: Event) {
+        self.events.push(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn render(&self) -> String {
+        let now = format_date_time(Utc::now());
+        let mut out = String::new();
+
+        push_line(&mut out, "BEGIN:VCALENDAR");
+        push_line(&mut out, "VERSION:2.0");
+        push_line(&mut out, "PRODID:-//gaia//Warframe schedules//EN");
+        push_line(&mut out, "CALSCALE:GREGORIAN");
+        push_line(&mut out, &format!("X-WR-CALNAME:{}", escape(&self.name)));
+
+        for event in &self.events {
+            push_line(&mut out, "BEGIN:VEVENT");
+            push_line(&mut out, &format!("UID:{}", event.uid));
+            push_line(&mut out, &format!("DTSTAMP:{now}"));
+            push_line(
+                &mut out,
+                &format!("DTSTART:{}", format_date_time(event.start)),
+            );
+            push_line(&mut out, &format!("DTEND:{}", format_date_time(event.end)));
+            push_line(&mut out, &format!("SUMMARY:{}", escape(&event.summary)));
+
+            if let Some(description) = &event.description {
+                push_line(&mut out, &format!("DESCRIPTION:{}", escape(description)));
+            }
+
+            if let Some(location) = &event.location {
+                push_line(&mut out, &format!("LOCATION:{}", escape(location)));
+            }
+
+            push_line(&mut out, "END:VEVENT");
+        }
+
+        push_line(&mut out, "END:VCALENDAR");
+
+        out
+    }
+}
+
+fn format_date_time(time: DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Appends `line`, folded into chunks of at most [`MAX_LINE_LENGTH`] octets.
+fn push_line(out: &mut String, line: &str) {
+    let mut length = 0;
+
+    for c in line.chars() {
+        if length + c.len_utf8() > MAX_LINE_LENGTH {
+            out.push_str("\r\n ");
+            // the leading space of a continuation line counts towards its length
+            length = 1;
+        }
+
+        out.push(c);
+        length += c.len_utf8();
+    }
+
+    out.push_str("\r\n");
+}
+
+#[test]
+fn long_lines_are_folded() {
+    let mut out = String::new();
+    push_line(&mut out, &format!("SUMMARY:{}", "ä".repeat(50)));
+
+    assert!(out.split("\r\n").all(|line| line.len() <= MAX_LINE_LENGTH));
+    assert_eq!(
+        out.replace("\r\n ", ""),
+        format!("SUMMARY:{}\r\n", "ä".repeat(50))
+    );
+}
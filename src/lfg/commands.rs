@@ -0,0 +1,324 @@
+use arbitration_data::model::mapped::Tier;
+use chrono::{DateTime, TimeDelta, Utc};
+use poise::{
+    CreateReply,
+    command,
+    serenity_prelude::{AutocompleteChoice, ChannelId, Mentionable, MessageId, UserId},
+};
+use warframe::worldstate::queryable::Cetus;
+
+use crate::{
+    CmdRet,
+    Context,
+    commands::eidolons::night_starts,
+    i18n::{LocalizedChoice, t},
+    lfg::{
+        LfgActivity,
+        MAX_PLANNING,
+        Squad,
+        close_squad_message,
+        db::LfgDbExtension,
+        parse_start,
+        squad_buttons,
+        squad_embed,
+    },
+    settings::Preferences,
+    utils::ContextExt,
+};
+
+const DEFAULT_SLOTS: u32 = 4;
+
+/// How many upcoming S-tier Arbitrations are suggested as a start.
+const SUGGESTED_ARBITRATIONS: usize = 3;
+
+/// Suggests upcoming S-tier Arbitrations, the next Cetus night and a few relative times.
+///
+/// Every suggestion submits a unix timestamp, which [`parse_start`] understands.
+async fn autocomplete_start(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let prefs = ctx.preferences().await.unwrap_or_default();
+    let lang = prefs.language;
+    let now = Utc::now();
+    let partial = partial.to_lowercase();
+
+    let in_about = |start: DateTime<Utc>| {
+        let left = start - now;
+        t!(
+            lang,
+            "lfg.in_about",
+            hours = left.num_hours(),
+            minutes = left.num_minutes() % 60
+        )
+    };
+
+    let game_data = ctx.data().game_data();
+    let mut choices = game_data
+        .localized_arbi_data(lang)
+        .iter_upcoming()
+        .map(|(_, arbi)| arbi)
+        .filter(|arbi| matches!(arbi.tier, Tier::S) && arbi.activation > now)
+        .take(SUGGESTED_ARBITRATIONS)
+        .map(|arbi| {
+            let name = t!(
+                lang,
+                "lfg.start_arbitration",
+                node = arbi.node,
+                mission_type = arbi.mission_type,
+                time = in_about(arbi.activation)
+            );
+            (name, arbi.activation)
+        })
+        .collect::<Vec<_>>();
+
+    let next_night = match ctx.data().worldstate().fetch::<Cetus>().await {
+        Ok(cetus) => night_starts(&cetus).find(|start| *start > now),
+        Err(_) => None,
+    };
+
+    if let Some(night) = next_night {
+        choices.push((t!(lang, "lfg.start_night", time = in_about(night)), night));
+    }
+
+    choices.push((
+        t!(lang, "lfg.start_in_30m").to_owned(),
+        now + TimeDelta::minutes(30),
+    ));
+    choices.push((
+        t!(lang, "lfg.start_in_1h").to_owned(),
+        now + TimeDelta::hours(1),
+    ));
+
+    choices
+        .into_iter()
+        .filter(|(name, _)| name.to_lowercase().contains(&partial))
+        .map(|(name, start)| AutocompleteChoice::new(name, start.timestamp().to_string()))
+        .collect()
+}
+
+/// Autocompletes the squads the author can cancel.
+async fn autocomplete_own_squad(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let Some(guild_id) = ctx.guild_id() else {
+        return Vec::new();
+    };
+    let lang = ctx
+        .preferences()
+        .await
+        .map(|prefs| prefs.language)
+        .unwrap_or_default();
+    let author = ctx.author().id.get() as i64;
+    let partial = partial.to_lowercase();
+
+    ctx.db()
+        .get_squads_for_guild(guild_id.get() as i64)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|squad| squad.owner_id == author)
+        .map(|squad| {
+            let name = t!(
+                lang,
+                "lfg.cancel_choice",
+                id = squad.id,
+                activity = squad.activity.name_in(lang)
+            );
+            (name, squad.id)
+        })
+        .filter(|(name, _)| name.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|(name, id)| AutocompleteChoice::new(name, id))
+        .collect()
+}
+
+#[command(
+    slash_command,
+    subcommands("create", "list", "cancel"),
+    subcommand_required,
+    guild_only
+)]
+pub async fn lfg(_: Context<'_>) -> CmdRet {
+    Ok(())
+}
+
+/// Looks for a squad: posts a sign-up with join/leave buttons and pings everyone at the start
+#[command(slash_command)]
+pub async fn create(
+    ctx: Context<'_>,
+    #[description = "What the squad is for"] activity: LfgActivity,
+    #[description = "When to start: a suggestion, a time like 20:30 or a delay like 45m"]
+    #[autocomplete = autocomplete_start]
+    start: String,
+    #[description = "The squad size, including you (default: 4)"]
+    #[min = 2]
+    #[max = 4]
+    slots: Option<u32>,
+    #[description = "Anything your squad should know, e.g. required gear or relics"]
+    #[max_length = 200]
+    note: Option<String>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+    let now = Utc::now();
+
+    let Some(starts_at) = parse_start(&start, prefs.timezone, now) else {
+        return Err(t!(lang, "lfg.invalid_start", input = start).into());
+    };
+
+    if starts_at <= now || starts_at - now > MAX_PLANNING {
+        return Err(t!(
+            lang,
+            "lfg.start_out_of_range",
+            days = MAX_PLANNING.num_days()
+        )
+        .into());
+    }
+
+    let squad = Squad {
+        id: 0,
+        server_id: guild_id,
+        channel_id: ctx.channel_id().get() as i64,
+        message_id: None,
+        owner_id: ctx.author().id.get() as i64,
+        activity,
+        note,
+        starts_at,
+        slots: slots.unwrap_or(DEFAULT_SLOTS) as i64,
+    };
+
+    post_squad(ctx, squad).await
+}
+
+/// Stores `squad` and posts its message with the join/leave buttons in the current channel.
+///
+/// The message is shared with the whole channel, so it is never ephemeral and uses the guild's
+/// language, just like the updates after someone joins or leaves.
+pub async fn post_squad(ctx: Context<'_>, mut squad: Squad) -> CmdRet {
+    let guild_prefs = Preferences::for_guild(ctx.db(), squad.server_id).await?;
+
+    squad.id = ctx.db().create_squad(&squad).await?;
+
+    let reply = ctx
+        .send(
+            CreateReply::default()
+                .embed(squad_embed(&guild_prefs, &squad, &[squad.owner_id]))
+                .components(squad_buttons(&guild_prefs, squad.id)),
+        )
+        .await;
+
+    let message = match reply {
+        Ok(reply) => reply.message().await,
+        Err(e) => Err(e),
+    };
+
+    match message {
+        Ok(message) => {
+            ctx.db()
+                .set_squad_message(squad.id, message.id.get() as i64)
+                .await?;
+        },
+        Err(e) => {
+            ctx.db().delete_squad(squad.id).await?;
+            return Err(e.into());
+        },
+    }
+
+    Ok(())
+}
+
+/// Lists the squads that are looking for members on this server
+#[command(slash_command)]
+pub async fn list(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap();
+
+    let squads = ctx.db().get_squads_for_guild(guild_id.get() as i64).await?;
+
+    if squads.is_empty() {
+        ctx.send(
+            CreateReply::default()
+                .content(t!(lang, "lfg.none"))
+                .ephemeral(prefs.ephemeral),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let mut lines = Vec::with_capacity(squads.len());
+
+    for squad in &squads {
+        let members = ctx.db().get_squad_members(squad.id).await?;
+        let link = squad
+            .message_id
+            .map(|message_id| {
+                MessageId::new(message_id as u64)
+                    .link(ChannelId::new(squad.channel_id as u64), Some(guild_id))
+            })
+            .unwrap_or_default();
+
+        lines.push(t!(
+            lang,
+            "lfg.list_entry",
+            id = squad.id,
+            activity = squad.activity.name_in(lang),
+            time = prefs.format_time(squad.starts_at),
+            count = members.len(),
+            slots = squad.slots,
+            owner = UserId::new(squad.owner_id as u64).mention(),
+            link = link
+        ));
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                prefs
+                    .embed()
+                    .title(t!(lang, "lfg.list_title"))
+                    .description(lines.join("\n")),
+            )
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Cancels one of your squads
+#[command(slash_command)]
+pub async fn cancel(
+    ctx: Context<'_>,
+    #[description = "The squad to cancel"]
+    #[autocomplete = autocomplete_own_squad]
+    squad: i64,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+    let author = ctx.author().id.get() as i64;
+
+    let squad = ctx
+        .db()
+        .get_squad(squad)
+        .await?
+        .filter(|squad| squad.server_id == guild_id && squad.owner_id == author)
+        .ok_or_else(|| t!(lang, "lfg.not_owner"))?;
+
+    ctx.db().delete_squad(squad.id).await?;
+
+    // the squad message is shared, so it is kept in the guild's language
+    let guild_lang = Preferences::for_guild(ctx.db(), guild_id).await?.language;
+    let content = format!("**{}**", t!(guild_lang, "lfg.cancelled"));
+
+    if let Err(e) = close_squad_message(ctx.serenity_context(), &squad, &content).await {
+        tracing::warn!(error = %e, "Could not close LFG squad message");
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .content(t!(lang, "lfg.cancel_success", id = squad.id))
+            .ephemeral(prefs.ephemeral),
+    )
+    .await?;
+
+    Ok(())
+}
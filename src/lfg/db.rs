@@ -0,0 +1,289 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::lfg::{LfgActivity, Squad};
+
+pub trait LfgDbExtension {
+    /// Stores `squad` with its owner as the first member and returns its id.
+    ///
+    /// The `id` and `message_id` of `squad` are ignored.
+    fn create_squad(&self, squad: &Squad) -> impl Future<Output = Result<i64, sqlx::Error>> + Send;
+
+    fn set_squad_message(
+        &self,
+        squad_id: i64,
+        message_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn get_squad(
+        &self,
+        squad_id: i64,
+    ) -> impl Future<Output = Result<Option<Squad>, sqlx::Error>> + Send;
+
+    fn get_squads_for_guild(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Vec<Squad>, sqlx::Error>> + Send;
+
+    /// Every squad whose activity starts at or before `now`.
+    fn get_due_squads(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<Squad>, sqlx::Error>> + Send;
+
+    fn get_squad_members(
+        &self,
+        squad_id: i64,
+    ) -> impl Future<Output = Result<Vec<i64>, sqlx::Error>> + Send;
+
+    /// Adds the user to the squad if it has a free slot. Returns whether the user was added.
+    fn join_squad(
+        &self,
+        squad_id: i64,
+        user_id: i64,
+    ) -> impl Future<Output = Result<bool, sqlx::Error>> + Send;
+
+    fn leave_squad(
+        &self,
+        squad_id: i64,
+        user_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    /// Deletes the squad and, through the foreign key, its members.
+    fn delete_squad(&self, squad_id: i64) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_all_squads(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl LfgDbExtension for SqlitePool {
+    async fn create_squad(&self, squad: &Squad) -> Result<i64, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let squad_id = sqlx::query!(
+            "
+            INSERT INTO lfg_squads (
+                server_id,
+                channel_id,
+                owner_id,
+                activity,
+                note,
+                starts_at,
+                slots
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            ",
+            squad.server_id,
+            squad.channel_id,
+            squad.owner_id,
+            squad.activity,
+            squad.note,
+            squad.starts_at,
+            squad.slots
+        )
+        .execute(tx.as_mut())
+        .await?
+        .last_insert_rowid();
+
+        sqlx::query!(
+            "
+            INSERT INTO lfg_members (squad_id, user_id)
+            VALUES ($1, $2);
+            ",
+            squad_id,
+            squad.owner_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(squad_id)
+    }
+
+    async fn set_squad_message(&self, squad_id: i64, message_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            UPDATE lfg_squads
+            SET message_id = $2
+            WHERE id = $1;
+            ",
+            squad_id,
+            message_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn get_squad(&self, squad_id: i64) -> Result<Option<Squad>, sqlx::Error> {
+        sqlx::query_as!(
+            Squad,
+            r#"
+            SELECT
+                id,
+                server_id,
+                channel_id,
+                message_id,
+                owner_id,
+                activity as "activity: LfgActivity",
+                note,
+                starts_at as "starts_at: DateTime<Utc>",
+                slots
+            FROM lfg_squads
+            WHERE id = $1
+            "#,
+            squad_id
+        )
+        .fetch_optional(self)
+        .await
+    }
+
+    async fn get_squads_for_guild(&self, guild_id: i64) -> Result<Vec<Squad>, sqlx::Error> {
+        sqlx::query_as!(
+            Squad,
+            r#"
+            SELECT
+                id,
+                server_id,
+                channel_id,
+                message_id,
+                owner_id,
+                activity as "activity: LfgActivity",
+                note,
+                starts_at as "starts_at: DateTime<Utc>",
+                slots
+            FROM lfg_squads
+            WHERE server_id = $1
+            ORDER BY starts_at
+            "#,
+            guild_id
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn get_due_squads(&self, now: DateTime<Utc>) -> Result<Vec<Squad>, sqlx::Error> {
+        sqlx::query_as!(
+            Squad,
+            r#"
+            SELECT
+                id,
+                server_id,
+                channel_id,
+                message_id,
+                owner_id,
+                activity as "activity: LfgActivity",
+                note,
+                starts_at as "starts_at: DateTime<Utc>",
+                slots
+            FROM lfg_squads
+            WHERE starts_at <= $1
+            "#,
+            now
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn get_squad_members(&self, squad_id: i64) -> Result<Vec<i64>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "
+            SELECT user_id
+            FROM lfg_members
+            WHERE squad_id = $1
+            ORDER BY joined_at
+            ",
+            squad_id
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn join_squad(&self, squad_id: i64, user_id: i64) -> Result<bool, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        // the capacity is checked by the insert itself, so simultaneous joins can't overfill it
+        let result = sqlx::query!(
+            "
+            INSERT INTO lfg_members (squad_id, user_id)
+            SELECT $1, $2
+            WHERE (SELECT COUNT(*) FROM lfg_members WHERE squad_id = $1)
+                < (SELECT slots FROM lfg_squads WHERE id = $1)
+            ON CONFLICT (squad_id, user_id) DO NOTHING;
+            ",
+            squad_id,
+            user_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn leave_squad(&self, squad_id: i64, user_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM lfg_members
+            WHERE squad_id = $1 AND user_id = $2;
+            ",
+            squad_id,
+            user_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_squad(&self, squad_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM lfg_squads
+            WHERE id = $1;
+            ",
+            squad_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_all_squads(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM lfg_squads
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,433 @@
+pub mod commands;
+pub mod db;
+
+use std::time::Duration;
+
+use chrono::{DateTime, NaiveTime, TimeDelta, TimeZone, Utc};
+use chrono_tz::Tz;
+use poise::{
+    ChoiceParameter,
+    serenity_prelude::{
+        self,
+        ButtonStyle,
+        ChannelId,
+        ComponentInteraction,
+        CreateActionRow,
+        CreateButton,
+        CreateEmbed,
+        CreateEmbedFooter,
+        CreateInteractionResponse,
+        CreateInteractionResponseMessage,
+        CreateMessage,
+        EditMessage,
+        Mentionable,
+        MessageId,
+        UserId,
+    },
+};
+
+use crate::{
+    AppData,
+    Error,
+    i18n::{LocalizedChoice, t},
+    lfg::db::LfgDbExtension,
+    settings::Preferences,
+};
+
+/// How often the scheduler looks for squads whose activity starts.
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Squads that started longer ago than this (e.g. while the bot was offline) are removed
+/// without pinging their members.
+const PING_GRACE: TimeDelta = TimeDelta::minutes(15);
+
+/// How far ahead a squad can be planned.
+pub const MAX_PLANNING: TimeDelta = TimeDelta::days(7);
+
+/// The prefix of the `custom_id` of every LFG button.
+pub const COMPONENT_PREFIX: &str = "lfg:";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, sqlx::Type, ChoiceParameter)]
+#[repr(i64)]
+pub enum LfgActivity {
+    #[name = "Arbitration"]
+    #[name_localized("de", "Schiedsgericht")]
+    #[name_localized("fr", "Arbitrage")]
+    Arbitration,
+
+    #[name = "Eidolon Hunt"]
+    #[name_localized("de", "Eidolon-Jagd")]
+    #[name_localized("fr", "Chasse aux Eidolons")]
+    EidolonHunt,
+
+    #[name = "Archon Hunt"]
+    #[name_localized("de", "Archon-Jagd")]
+    #[name_localized("fr", "Chasse à l'Archonte")]
+    ArchonHunt,
+
+    #[name = "Fissure Run"]
+    #[name_localized("de", "Riss-Runs")]
+    #[name_localized("fr", "Fissures du Néant")]
+    FissureRun,
+
+    #[name = "Relic Radshare"]
+    #[name_localized("de", "Relikt-Radshare")]
+    #[name_localized("fr", "Radshare de reliques")]
+    RelicRadshare,
+}
+
+/// A squad posted with `/lfg create`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Squad {
+    pub id: i64,
+    pub server_id: i64,
+    pub channel_id: i64,
+    /// The message with the join/leave buttons, once it has been posted.
+    pub message_id: Option<i64>,
+    pub owner_id: i64,
+    pub activity: LfgActivity,
+    pub note: Option<String>,
+    pub starts_at: DateTime<Utc>,
+    /// The squad size, including the owner.
+    pub slots: i64,
+}
+
+/// What a press on one of the squad buttons does.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SquadAction {
+    Join,
+    Leave,
+}
+
+impl SquadAction {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Join => "join",
+            Self::Leave => "leave",
+        }
+    }
+
+    fn custom_id(self, squad_id: i64) -> String {
+        format!("{COMPONENT_PREFIX}{}:{squad_id}", self.as_str())
+    }
+
+    /// The action and squad id encoded in `custom_id` by [`SquadAction::custom_id`].
+    fn parse(custom_id: &str) -> Option<(Self, i64)> {
+        let (action, squad_id) = custom_id.strip_prefix(COMPONENT_PREFIX)?.split_once(':')?;
+
+        let action = match action {
+            "join" => Self::Join,
+            "leave" => Self::Leave,
+            _ => return None,
+        };
+
+        Some((action, squad_id.parse().ok()?))
+    }
+}
+
+fn mentions(users: &[i64]) -> String {
+    users
+        .iter()
+        .map(|user| UserId::new(*user as u64).mention().to_string())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn squad_embed(prefs: &Preferences, squad: &Squad, members: &[i64]) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let embed = prefs
+        .embed()
+        .title(t!(
+            lang,
+            "lfg.title",
+            activity = squad.activity.name_in(lang)
+        ))
+        .field(
+            t!(lang, "common.starts"),
+            prefs.format_time(squad.starts_at),
+            true,
+        )
+        .field(
+            t!(lang, "lfg.host"),
+            UserId::new(squad.owner_id as u64).mention().to_string(),
+            true,
+        )
+        .field(
+            t!(
+                lang,
+                "lfg.members",
+                count = members.len(),
+                slots = squad.slots
+            ),
+            mentions(members),
+            false,
+        )
+        .footer(CreateEmbedFooter::new(t!(
+            lang,
+            "lfg.footer",
+            id = squad.id
+        )));
+
+    match &squad.note {
+        Some(note) => embed.description(note),
+        None => embed,
+    }
+}
+
+pub fn squad_buttons(prefs: &Preferences, squad_id: i64) -> Vec<CreateActionRow> {
+    let lang = prefs.language;
+
+    vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(SquadAction::Join.custom_id(squad_id))
+            .label(t!(lang, "lfg.join"))
+            .style(ButtonStyle::Success),
+        CreateButton::new(SquadAction::Leave.custom_id(squad_id))
+            .label(t!(lang, "lfg.leave"))
+            .style(ButtonStyle::Secondary),
+    ])]
+}
+
+/// Parses the start of a squad from what was entered or autocompleted in `/lfg create`.
+///
+/// Accepts a unix timestamp (what the autocomplete submits), a relative time like `30m` or `2h`,
+/// or a time of day like `20:30` in `timezone` (UTC if unset), which is the next occurrence of it.
+pub fn parse_start(input: &str, timezone: Option<Tz>, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    let input = input.trim().to_lowercase();
+
+    if let Ok(timestamp) = input.parse::<i64>() {
+        return DateTime::from_timestamp(timestamp, 0);
+    }
+
+    // huge delays are rejected here rather than overflowing, which would panic
+    if let Some(minutes) = input.strip_suffix('m').and_then(|n| n.trim().parse().ok()) {
+        return TimeDelta::try_minutes(minutes).and_then(|delay| now.checked_add_signed(delay));
+    }
+
+    if let Some(hours) = input.strip_suffix('h').and_then(|n| n.trim().parse().ok()) {
+        return TimeDelta::try_hours(hours).and_then(|delay| now.checked_add_signed(delay));
+    }
+
+    let time = NaiveTime::parse_from_str(&input, "%H:%M").ok()?;
+    let timezone = timezone.unwrap_or(Tz::UTC);
+    let today = now.with_timezone(&timezone).date_naive();
+
+    [today, today.succ_opt()?]
+        .into_iter()
+        .filter_map(|date| {
+            timezone
+                .from_local_datetime(&date.and_time(time))
+                .earliest()
+        })
+        .map(|start| start.with_timezone(&Utc))
+        .find(|start| *start > now)
+}
+
+/// Handles the join/leave buttons of squad messages.
+///
+/// The squad is looked up by the id in the `custom_id`, so the buttons keep working across
+/// restarts for as long as the squad exists.
+pub async fn handle_component(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    press: &ComponentInteraction,
+) -> Result<(), Error> {
+    let Some((action, squad_id)) = SquadAction::parse(&press.data.custom_id) else {
+        return Ok(());
+    };
+
+    let user_id = press.user.id.get() as i64;
+    let user_lang = Preferences::resolve(
+        data.db(),
+        user_id,
+        press.guild_id.map(|id| id.get() as i64),
+        Some(&press.locale),
+    )
+    .await?
+    .language;
+
+    let reply = |content: &'static str| {
+        CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new()
+                .content(content)
+                .ephemeral(true),
+        )
+    };
+
+    let Some(squad) = data.db().get_squad(squad_id).await? else {
+        press
+            .create_response(ctx, reply(t!(user_lang, "lfg.gone")))
+            .await?;
+        return Ok(());
+    };
+
+    let members = data.db().get_squad_members(squad_id).await?;
+    let is_member = members.contains(&user_id);
+
+    let refusal = match action {
+        SquadAction::Join if is_member => Some(t!(user_lang, "lfg.already_joined")),
+        SquadAction::Join if members.len() as i64 >= squad.slots => Some(t!(user_lang, "lfg.full")),
+        SquadAction::Leave if user_id == squad.owner_id => Some(t!(user_lang, "lfg.owner_leave")),
+        SquadAction::Leave if !is_member => Some(t!(user_lang, "lfg.not_joined")),
+        _ => None,
+    };
+
+    if let Some(refusal) = refusal {
+        press.create_response(ctx, reply(refusal)).await?;
+        return Ok(());
+    }
+
+    match action {
+        SquadAction::Join => {
+            // someone else may have taken the last slot since the members were loaded
+            if !data.db().join_squad(squad_id, user_id).await? {
+                press
+                    .create_response(ctx, reply(t!(user_lang, "lfg.full")))
+                    .await?;
+                return Ok(());
+            }
+        },
+        SquadAction::Leave => data.db().leave_squad(squad_id, user_id).await?,
+    }
+
+    // the message is shared, so it is kept in the guild's language
+    let prefs = Preferences::for_guild(data.db(), squad.server_id).await?;
+    let members = data.db().get_squad_members(squad_id).await?;
+
+    let update =
+        CreateInteractionResponseMessage::new().embed(squad_embed(&prefs, &squad, &members));
+
+    press
+        .create_response(ctx, CreateInteractionResponse::UpdateMessage(update))
+        .await?;
+
+    Ok(())
+}
+
+/// Removes the buttons from a squad's message and puts `content` above its embed.
+pub async fn close_squad_message(
+    ctx: &serenity_prelude::Context,
+    squad: &Squad,
+    content: &str,
+) -> Result<(), serenity_prelude::Error> {
+    let Some(message_id) = squad.message_id else {
+        return Ok(());
+    };
+
+    ChannelId::new(squad.channel_id as u64)
+        .edit_message(
+            ctx,
+            MessageId::new(message_id as u64),
+            EditMessage::new().content(content).components(Vec::new()),
+        )
+        .await
+        .map(|_| ())
+}
+
+/// Pings the members of every squad whose activity starts and removes the squad afterwards.
+pub async fn run_scheduler(ctx: serenity_prelude::Context, data: AppData) {
+    let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let now = Utc::now();
+        let squads = match data.db().get_due_squads(now).await {
+            Ok(squads) => squads,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not fetch due LFG squads");
+                continue;
+            },
+        };
+
+        for squad in squads {
+            if let Err(e) = start_squad(&ctx, &data, &squad, now).await {
+                tracing::warn!(error = %e, squad = squad.id, "Could not start LFG squad");
+            }
+
+            // a squad that could not be announced is still over
+            if let Err(e) = data.db().delete_squad(squad.id).await {
+                tracing::warn!(error = %e, squad = squad.id, "Could not delete LFG squad");
+            }
+        }
+    }
+}
+
+async fn start_squad(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    squad: &Squad,
+    now: DateTime<Utc>,
+) -> Result<(), Error> {
+    let lang = Preferences::for_guild(data.db(), squad.server_id)
+        .await?
+        .language;
+
+    close_squad_message(ctx, squad, &format!("**{}**", t!(lang, "lfg.started"))).await?;
+
+    if now - squad.starts_at > PING_GRACE {
+        return Ok(());
+    }
+
+    let members = data.db().get_squad_members(squad.id).await?;
+    let channel_id = ChannelId::new(squad.channel_id as u64);
+
+    let message = CreateMessage::new().content(format!(
+        "{} {}",
+        t!(
+            lang,
+            "lfg.starting",
+            activity = squad.activity.name_in(lang)
+        ),
+        mentions(&members)
+    ));
+
+    let message = match squad.message_id {
+        Some(message_id) => {
+            message.reference_message((channel_id, MessageId::new(message_id as u64)))
+        },
+        None => message,
+    };
+
+    channel_id.send_message(ctx, message).await?;
+
+    Ok(())
+}
+
+#[test]
+fn start_times_are_parsed() {
+    let now = DateTime::from_timestamp(1_760_000_000, 0).unwrap(); // 2025-10-09 08:53:20 UTC
+
+    assert_eq!(
+        parse_start("1760003600", None, now),
+        DateTime::from_timestamp(1_760_003_600, 0)
+    );
+    assert_eq!(
+        parse_start("30m", None, now),
+        Some(now + TimeDelta::minutes(30))
+    );
+    assert_eq!(
+        parse_start(" 2H ", None, now),
+        Some(now + TimeDelta::hours(2))
+    );
+
+    // later today, and tomorrow once the time has passed
+    assert_eq!(
+        parse_start("20:30", None, now),
+        DateTime::from_timestamp(1_760_041_800, 0)
+    );
+    assert_eq!(
+        parse_start("08:00", None, now),
+        DateTime::from_timestamp(1_760_083_200, 0)
+    );
+
+    // 20:30 in Berlin (UTC+2 in October) is 18:30 UTC
+    assert_eq!(
+        parse_start("20:30", Some(Tz::Europe__Berlin), now),
+        DateTime::from_timestamp(1_760_034_600, 0)
+    );
+
+    assert_eq!(parse_start("soon", None, now), None);
+    assert_eq!(parse_start("99999999999999h", None, now), None);
+    assert_eq!(parse_start("-9223372036854775808m", None, now), None);
+}
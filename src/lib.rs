@@ -1,12 +1,20 @@
+pub mod arbitration;
 pub mod closest;
 pub mod commands;
+pub mod components;
 pub mod emojis;
+pub mod game_data;
+pub mod i18n;
+pub mod ical;
+pub mod lfg;
 pub mod notifier;
+pub mod reminders;
+pub mod schedule_monitor;
+pub mod settings;
 pub mod utils;
 
 use std::{env, path::PathBuf, sync::Arc, time::Duration};
 
-use arbitration_data::model::{dict::LanguageDict, regions::ExportRegions};
 use derive_more::Debug;
 use moka::future::Cache;
 use poise::{
@@ -14,9 +22,15 @@ use poise::{
     serenity_prelude::{self, CreateEmbed, colours::roles::DARK_RED},
 };
 use sqlx::SqlitePool;
+use tokio::sync::watch;
 use warframe::{market, worldstate};
 
-use crate::commands::market::average::Statistics;
+use crate::{
+    commands::market::average::Statistics,
+    game_data::{GameData, data_dir},
+    i18n::{Language, t},
+    settings::Preferences,
+};
 
 pub type Error = Box<dyn std::error::Error + Send + Sync>;
 pub type CmdRet = std::result::Result<(), Error>;
@@ -29,29 +43,19 @@ pub struct AppData {
     worldstate: worldstate::Client,
     market: Arc<market::Client>,
     #[debug(skip)]
-    arbi_data: Arc<arbitration_data::ArbitrationData>,
+    game_data: Arc<watch::Sender<Arc<GameData>>>,
     db: SqlitePool,
     market_statistic_cache: Cache<String, Statistics>,
 }
 
 impl AppData {
     pub fn try_new_auto(pool: SqlitePool) -> Result<Self, Error> {
-        let arbi_time_node_mapping =
-            csv::Reader::from_reader(include_str!("../arbys.csv").as_bytes());
-        let export_regions: ExportRegions<'_> =
-            serde_json::from_str(include_str!("../regions.json"))?;
-        let language_dict: LanguageDict = serde_json::from_str(include_str!("../dict.en.json"))?;
-
-        let arbi_data = arbitration_data::ArbitrationData::new(
-            arbi_time_node_mapping,
-            export_regions,
-            language_dict,
-        )?;
+        let game_data = GameData::load(&data_dir())?;
 
         Ok(Self {
             worldstate: worldstate::Client::default(),
             market: Arc::new(market::Client::new()),
-            arbi_data: Arc::new(arbi_data),
+            game_data: Arc::new(watch::Sender::new(Arc::new(game_data))),
             db: pool,
             market_statistic_cache: Cache::builder()
                 .time_to_live(Duration::from_secs(60 * 60))
@@ -72,8 +76,29 @@ impl AppData {
         &self.market_statistic_cache
     }
 
-    pub fn arbi_data(&self) -> &arbitration_data::ArbitrationData {
-        &self.arbi_data
+    /// The currently loaded [`GameData`].
+    ///
+    /// Keep the returned `Arc` for the duration of a command, as a reload may swap the data
+    /// in between two calls.
+    pub fn game_data(&self) -> Arc<GameData> {
+        self.game_data.borrow().clone()
+    }
+
+    /// A receiver that is notified whenever [`AppData::reload_game_data`] swaps in new data.
+    pub fn subscribe_game_data(&self) -> watch::Receiver<Arc<GameData>> {
+        self.game_data.subscribe()
+    }
+
+    /// Loads the data files from [`data_dir`] again and swaps them in.
+    ///
+    /// The current data stays in place if loading fails.
+    pub async fn reload_game_data(&self) -> Result<Arc<GameData>, Error> {
+        let game_data =
+            Arc::new(tokio::task::spawn_blocking(|| GameData::load(&data_dir())).await??);
+
+        self.game_data.send_replace(game_data.clone());
+
+        Ok(game_data)
     }
 
     pub fn db(&self) -> &SqlitePool {
@@ -105,10 +130,20 @@ pub async fn handle_command_error(
     err: Error,
     ctx: poise::Context<'_, AppData, Error>,
 ) -> Result<(), serenity_prelude::Error> {
+    let lang = Preferences::resolve(
+        ctx.data().db(),
+        ctx.author().id.get() as i64,
+        ctx.guild_id().map(|id| id.get() as i64),
+        ctx.locale(),
+    )
+    .await
+    .unwrap_or_default()
+    .language;
+
     ctx.send(
         CreateReply::default().embed(
             CreateEmbed::default()
-                .title("Error")
+                .title(t!(lang, "common.error"))
                 .description(err.to_string())
                 .color(DARK_RED),
         ),
@@ -3,22 +3,52 @@ use std::{collections::HashSet, str::FromStr};
 use gaia::{
     AppData,
     Error,
+    arbitration::db::ArbitrationDbExtension,
     commands::{
+        alerts::alerts,
         arbi::{upcoming_arbitration, upcoming_arbitrations},
+        arbitration_stats::arbitration_stats,
         archon_hunt::archon_hunt,
+        bounties::bounties,
+        calendar::calendar,
+        darvo::darvo,
+        duviri::duviri,
+        eidolons::eidolons,
         event::events,
+        fissures::{fissures, void_storms},
         market::market,
+        nightwave::nightwave,
+        node::node,
+        owner::reload_data,
+        steel_path::steel_path,
         worldstate::worldstate,
     },
+    components,
     handle_error,
     init_db,
-    notifier,
+    lfg::{self, commands::lfg, db::LfgDbExtension},
+    notifier::{
+        self,
+        db::{
+            DealFilterDbExtension,
+            EventMessageDbExtension,
+            NewsDbExtension,
+            RewardFilterDbExtension,
+            VoidStormDbExtension,
+        },
+    },
+    reminders::{self, commands::remind, db::ReminderDbExtension},
+    schedule_monitor::monitor_schedule,
+    settings::{
+        commands::{preferences, settings},
+        db::SettingsDbExtension,
+    },
     utils::DbExtension,
 };
 use poise::{
     FrameworkContext,
     FrameworkError,
-    serenity_prelude::{self, ClientBuilder, FullEvent, GatewayIntents, UserId},
+    serenity_prelude::{self, ClientBuilder, FullEvent, GatewayIntents, Interaction, UserId},
 };
 use tokio::signal;
 use tracing::level_filters::LevelFilter;
@@ -47,10 +77,27 @@ async fn main() -> Result<(), Error> {
                 worldstate(),
                 upcoming_arbitration(),
                 upcoming_arbitrations(),
+                node(),
+                arbitration_stats(),
                 archon_hunt(),
+                steel_path(),
+                nightwave(),
+                duviri(),
+                darvo(),
+                fissures(),
+                void_storms(),
+                alerts(),
+                bounties(),
+                eidolons(),
+                lfg(),
+                remind(),
+                calendar(),
                 notifier::commands::notifier(),
                 market(),
                 events(),
+                reload_data(),
+                settings(),
+                preferences(),
             ],
             event_handler: |ctx, event, framework, data| {
                 Box::pin(event_handler(ctx, event, framework, data))
@@ -63,6 +110,13 @@ async fn main() -> Result<(), Error> {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
                 notifier::setup(ctx.clone(), data_clone.clone()).await?;
+                tokio::spawn(monitor_schedule(
+                    ctx.clone(),
+                    data_clone.clone(),
+                    framework.options().owners.clone(),
+                ));
+                tokio::spawn(lfg::run_scheduler(ctx.clone(), data_clone.clone()));
+                tokio::spawn(reminders::run_scheduler(ctx.clone(), data_clone.clone()));
 
                 Ok(data_clone.clone())
             })
@@ -90,16 +144,47 @@ async fn main() -> Result<(), Error> {
 }
 
 async fn event_handler(
-    _ctx: &serenity_prelude::Context,
+    ctx: &serenity_prelude::Context,
     event: &FullEvent,
     _framework: FrameworkContext<'_, AppData, Error>,
     data: &AppData,
 ) -> Result<(), Error> {
-    // Remove all db entries for that guild upon the bot leaving/guild being deleted
-    if let FullEvent::GuildDelete { incomplete, .. } = event {
-        data.db()
-            .delete_all_subscriptions(incomplete.id.get() as i64)
-            .await?
+    // Remove all db entries for that guild upon the bot leaving/guild being deleted. A guild that
+    // is only unavailable because of an outage keeps its configuration.
+    let removed_guild = match event {
+        FullEvent::GuildDelete { incomplete, .. } if !incomplete.unavailable => {
+            Some(incomplete.id.get() as i64)
+        },
+        _ => None,
+    };
+
+    if let Some(guild_id) = removed_guild {
+        data.db().delete_all_subscriptions(guild_id).await?;
+
+        data.db().delete_guild_settings(guild_id).await?;
+
+        data.db().delete_arbitration_criteria(guild_id).await?;
+
+        data.db().delete_all_reward_filters(guild_id).await?;
+
+        data.db().delete_minimum_discount(guild_id).await?;
+
+        data.db().delete_news_categories(guild_id).await?;
+
+        data.db().delete_all_event_messages(guild_id).await?;
+
+        data.db().delete_void_storm_filter(guild_id).await?;
+
+        data.db().delete_all_squads(guild_id).await?;
+
+        data.db().delete_channel_reminders(guild_id).await?;
+    }
+
+    if let FullEvent::InteractionCreate {
+        interaction: Interaction::Component(press),
+    } = event
+    {
+        components::route(ctx, data, press).await?;
     }
 
     Ok(())
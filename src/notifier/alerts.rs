@@ -0,0 +1,67 @@
+use poise::serenity_prelude::{self, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{Change, queryable::Alert};
+
+use crate::{
+    AppData,
+    Error,
+    commands::{alerts::alert_embed, event::reward_items},
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::RewardFilterDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+        wants_reward,
+    },
+};
+
+pub struct Alerts;
+
+impl Notifier for Alerts {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_nested_update_with_state::<_, Alert, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    alert: &Alert,
+    change: Change,
+) {
+    if change != Change::Added {
+        return;
+    }
+
+    let filters = data
+        .db()
+        .get_reward_filters(SubscriptionType::Alerts)
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::Alerts,
+        |sub| wants_reward(&filters, sub, reward_items(&alert.mission.reward)),
+        |prefs| alert_embed(prefs, alert).timestamp(Timestamp::now()),
+    )
+    .await
+    .unwrap_or_default();
+}
@@ -0,0 +1,119 @@
+use arbitration_data::model::mapped::ArbitrationInfo;
+use chrono::Utc;
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    AppData,
+    Error,
+    arbitration::db::ArbitrationDbExtension,
+    game_data::GameData,
+    i18n::t,
+    notifier::{Notifier, error::NotifierError, model::SubscriptionType, notify_subscribers_where},
+    settings::Preferences,
+};
+
+fn build_embed(prefs: &Preferences, arbi: &ArbitrationInfo) -> CreateEmbed {
+    let lang = prefs.language;
+
+    prefs
+        .embed()
+        .title(t!(lang, "notifier.arbitration.title", tier = arbi.tier))
+        .field(
+            t!(lang, "arbi.node"),
+            format!("{} ({})", &arbi.node, &arbi.planet),
+            true,
+        )
+        .field(
+            t!(lang, "arbi.mission_type"),
+            t!(
+                lang,
+                "arbi.mission_against",
+                mission_type = arbi.mission_type,
+                faction = arbi.faction
+            ),
+            true,
+        )
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(arbi.expiry),
+            false,
+        )
+        .timestamp(Timestamp::now())
+}
+
+/// Walks the arbitration schedule once and notifies every guild whose
+/// [`ArbitrationCriteria`](crate::arbitration::ArbitrationCriteria) an arbitration matches.
+///
+/// Starts over with the new schedule whenever the game data is reloaded.
+pub struct ArbitrationScheduler;
+
+impl Notifier for ArbitrationScheduler {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        let mut reloads = data.subscribe_game_data();
+
+        loop {
+            let game_data = reloads.borrow_and_update().clone();
+
+            tokio::select! {
+                result = notify_upcoming(&ctx, &data, &tx, &game_data) => {
+                    result?;
+                    tracing::warn!("Arbitration schedule exhausted, waiting for new data");
+                    reloads.changed().await?;
+                },
+                changed = reloads.changed() => {
+                    changed?;
+                    tracing::info!("Arbitration data reloaded, rescheduling");
+                },
+            }
+        }
+    }
+}
+
+async fn notify_upcoming(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    tx: &UnboundedSender<NotifierError>,
+    game_data: &GameData,
+) -> Result<(), Error> {
+    for (key, next_arbi) in game_data.arbi_data().iter_upcoming() {
+        if next_arbi.activation > Utc::now() {
+            tracing::info!(time_to_sleep = ?(next_arbi.activation - Utc::now()).to_std()?, upcoming_arbi = ?next_arbi);
+            tokio::time::sleep((next_arbi.activation - Utc::now()).to_std()?).await;
+        }
+
+        // loaded per arbitration, so that changes apply without a restart
+        let criteria = data.db().get_all_arbitration_criteria().await?;
+
+        notify_subscribers_where(
+            ctx,
+            data,
+            tx,
+            SubscriptionType::Arbitrations,
+            |sub| {
+                criteria
+                    .get(&(sub.server_id.get() as i64))
+                    .cloned()
+                    .unwrap_or_default()
+                    .matches(next_arbi)
+            },
+            |prefs| {
+                // criteria match the English names, but guilds get the same row in their language
+                let localized = game_data
+                    .localized_arbi_data(prefs.language)
+                    .iter_upcoming()
+                    .find(|(localized_key, _)| *localized_key == key)
+                    .map_or(next_arbi, |(_, arbi)| arbi);
+
+                build_embed(prefs, localized)
+            },
+        )
+        .await?;
+    }
+
+    Ok(())
+}
@@ -1,5 +1,4 @@
 use poise::{
-    ChoiceParameter,
     CreateReply,
     command,
     serenity_prelude::{GuildChannel, Mentionable, RoleId},
@@ -8,13 +7,50 @@ use poise::{
 use crate::{
     CmdRet,
     Context,
-    notifier::model::SubscriptionType,
-    utils::{self, ContextExt, DbExtension},
+    arbitration::{ArbitrationCriteria, ArbitrationFilter, db::ArbitrationDbExtension},
+    commands::{
+        arbi::{
+            UserArbitrationTier,
+            autocomplete_faction,
+            autocomplete_mission_type,
+            autocomplete_node,
+            autocomplete_planet,
+            resolve_filter,
+        },
+        bounties::autocomplete_english_reward,
+        fissures::{FissureTier, ProximaRegion},
+        steel_path::autocomplete_honors_reward,
+    },
+    i18n::{Language, LocalizedChoice, t},
+    notifier::{
+        db::{
+            DealFilterDbExtension,
+            NewsDbExtension,
+            RewardFilterDbExtension,
+            VoidStormDbExtension,
+        },
+        model::SubscriptionType,
+        news::NewsCategory,
+        void_storms::VoidStormFilter,
+    },
+    utils::{ContextExt, DbExtension},
 };
 
 #[command(
     slash_command,
-    subcommands("setup", "remove", "list"),
+    subcommands(
+        "setup",
+        "remove",
+        "list",
+        "arbitrations",
+        "steel_path_honors",
+        "incarnons",
+        "darvo",
+        "news",
+        "alerts",
+        "void_storms",
+        "bounties"
+    ),
     subcommand_required,
     default_member_permissions = "ADMINISTRATOR",
     guild_only
@@ -38,6 +74,8 @@ pub async fn setup(
     #[rename = "role_to_mention"]
     role_id_to_mention: Option<RoleId>,
 ) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let guild_id = ctx.guild_id().unwrap().get() as i64;
     let channel_id = channel.id.get() as i64;
 
@@ -52,14 +90,22 @@ pub async fn setup(
 
     ctx.send(
         CreateReply::default().reply(true).embed(
-            utils::embed()
-                .title("Setup Successful")
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.setup_title"))
                 .description(format!(
-                    "You will now receive notifications for `{}` in {}.{}",
-                    subscription.name(),
-                    channel.mention(),
+                    "{}{}",
+                    t!(
+                        lang,
+                        "notifier.setup_description",
+                        subscription = subscription.name_in(lang),
+                        channel = channel.mention()
+                    ),
                     role_id_to_mention
-                        .map(|v| format!("\nRole to mention upon notification: {}", v.mention()))
+                        .map(|v| format!(
+                            "\n{}",
+                            t!(lang, "notifier.role_to_mention", role = v.mention())
+                        ))
                         .unwrap_or_else(|| "".into())
                 )),
         ),
@@ -76,17 +122,21 @@ pub async fn remove(
     #[description = "The type of notifications to unsubscribe from."]
     subscription: SubscriptionType,
 ) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let guild_id = ctx.guild_id().unwrap().get() as i64;
 
     ctx.db().delete_subscription(subscription, guild_id).await?;
 
     ctx.send(
         CreateReply::default().reply(true).embed(
-            utils::embed()
-                .title("Successfully Unsubscribed")
-                .description(format!(
-                    "You will no longer receive notifications for `{}` in this server.",
-                    subscription.name()
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.unsubscribed_title"))
+                .description(t!(
+                    lang,
+                    "notifier.unsubscribed_description",
+                    subscription = subscription.name_in(lang)
                 )),
         ),
     )
@@ -98,15 +148,18 @@ pub async fn remove(
 /// Unsubscribe from ALL events
 #[command(slash_command)]
 pub async fn remove_all(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let guild_id = ctx.guild_id().unwrap().get() as i64;
 
     ctx.db().delete_all_subscriptions(guild_id).await?;
 
     ctx.send(
         CreateReply::default().reply(true).embed(
-            utils::embed()
-                .title("Successfully Unsubscribed")
-                .description("You will no longer receive notifications in this server."),
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.unsubscribed_title"))
+                .description(t!(lang, "notifier.unsubscribed_all")),
         ),
     )
     .await?;
@@ -117,15 +170,28 @@ pub async fn remove_all(ctx: Context<'_>) -> CmdRet {
 /// List current subscriptions in the server.
 #[command(slash_command)]
 pub async fn list(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
     let guild_id = ctx.guild_id().unwrap().get() as i64;
 
     let subscriptions = ctx.db().get_subscriptions_for_guild(guild_id).await?;
+    let arbitration_criteria = ctx
+        .db()
+        .get_arbitration_criteria(guild_id)
+        .await?
+        .unwrap_or_default();
+    let reward_filters = ctx.db().get_reward_filters_for_guild(guild_id).await?;
+    let minimum_discount = ctx.db().get_minimum_discount(guild_id).await?;
+    let news_categories = ctx.db().get_news_categories(guild_id).await?;
+    let void_storm_filter = ctx.db().get_void_storm_filter(guild_id).await?;
 
     if subscriptions.is_empty() {
         ctx.send(
-            CreateReply::default()
-                .reply(true)
-                .embed(utils::embed().description("You have no subscriptions in this server.")),
+            CreateReply::default().reply(true).embed(
+                prefs
+                    .embed()
+                    .description(t!(lang, "notifier.no_subscriptions")),
+            ),
         )
         .await?;
         return Ok(());
@@ -133,22 +199,404 @@ pub async fn list(ctx: Context<'_>) -> CmdRet {
 
     ctx.send(
         CreateReply::default().reply(true).embed(
-            utils::embed().title("Current Subscriptions").description(
-                subscriptions
-                    .iter()
-                    .map(|s| {
-                        format!(
-                            "- `{}` in {}{}",
-                            s.subscription_type.name(),
-                            s.notification_channel_id.mention(),
-                            s.role_id_to_mention
-                                .map(|v| format!(" mentioning {}", v.mention()))
-                                .unwrap_or_else(|| "".into())
-                        )
-                    })
-                    .collect::<Vec<_>>()
-                    .join("\n"),
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.list_title"))
+                .description(
+                    subscriptions
+                        .iter()
+                        .map(|s| {
+                            format!(
+                                "- {}{}",
+                                t!(
+                                    lang,
+                                    "notifier.list_entry",
+                                    subscription = s.subscription_type.name_in(lang),
+                                    channel = s.notification_channel_id.mention()
+                                ),
+                                s.role_id_to_mention
+                                    .map(|v| t!(lang, "notifier.list_mention", role = v.mention()))
+                                    .unwrap_or_else(|| "".into())
+                            ) + &match s.subscription_type {
+                                SubscriptionType::Arbitrations => format!(
+                                    " ({})",
+                                    describe_criteria(lang, &arbitration_criteria).join(", ")
+                                ),
+                                SubscriptionType::DarvoDeals => minimum_discount
+                                    .map(|discount| {
+                                        format!(
+                                            " ({}: `{discount}%`)",
+                                            t!(lang, "notifier.minimum_discount")
+                                        )
+                                    })
+                                    .unwrap_or_default(),
+                                SubscriptionType::VoidStorms => void_storm_filter
+                                    .map(|filter| {
+                                        format!(" ({})", describe_void_storm_filter(lang, &filter))
+                                    })
+                                    .unwrap_or_default(),
+                                SubscriptionType::News if !news_categories.is_empty() => {
+                                    format!(
+                                        " ({})",
+                                        describe_news_categories(lang, &news_categories)
+                                    )
+                                },
+                                subscription_type => reward_filters
+                                    .get(&subscription_type)
+                                    .map(|rewards| {
+                                        format!(
+                                            " ({}: {})",
+                                            t!(lang, "notifier.rewards"),
+                                            describe_rewards(rewards)
+                                        )
+                                    })
+                                    .unwrap_or_default(),
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                        .join("\n"),
+                ),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// The minimum tier and every set filter of `criteria` as `Name: `value`` entries.
+fn describe_criteria(lang: Language, criteria: &ArbitrationCriteria) -> Vec<String> {
+    let minimum_tier = format!(
+        "{}: `{}`",
+        t!(lang, "notifier.arbitration_minimum_tier"),
+        criteria.minimum_tier().name_in(lang)
+    );
+
+    std::iter::once(minimum_tier)
+        .chain(
+            criteria
+                .filter
+                .describe()
+                .map(|(field, value)| format!("{}: `{value}`", field.name_in(lang))),
+        )
+        .collect()
+}
+
+/// Choose which Arbitrations to be notified about. Leave all empty to reset to S-Tier only.
+#[command(slash_command)]
+pub async fn arbitrations(
+    ctx: Context<'_>,
+    #[description = "Notify about Arbitrations of this Tier or better (default: S)"]
+    minimum_tier: Option<UserArbitrationTier>,
+    #[description = "Only notify about this mission type"]
+    #[autocomplete = autocomplete_mission_type]
+    mission_type: Option<String>,
+    #[description = "Only notify about Arbitrations against this faction"]
+    #[autocomplete = autocomplete_faction]
+    faction: Option<String>,
+    #[description = "Only notify about Arbitrations on this planet"]
+    #[autocomplete = autocomplete_planet]
+    planet: Option<String>,
+    #[description = "Only notify about Arbitrations on this node"]
+    #[autocomplete = autocomplete_node]
+    node: Option<String>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+
+    let criteria = ArbitrationCriteria {
+        minimum_tier,
+        filter: resolve_filter(
+            ctx,
+            lang,
+            ArbitrationFilter {
+                mission_type,
+                faction,
+                planet,
+                node,
+            },
+        )?,
+    };
+
+    let description = if criteria == ArbitrationCriteria::default() {
+        ctx.db().delete_arbitration_criteria(guild_id).await?;
+        t!(lang, "notifier.arbitration_criteria_reset").to_owned()
+    } else {
+        ctx.db()
+            .save_arbitration_criteria(guild_id, &criteria)
+            .await?;
+        format!(
+            "{}\n- {}",
+            t!(lang, "notifier.arbitration_criteria_set"),
+            describe_criteria(lang, &criteria).join("\n- ")
+        )
+    };
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.arbitration_criteria_title"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Choose which Steel Path honors rewards to be notified about. Leave empty to reset.
+#[command(slash_command, rename = "steel-path-honors")]
+pub async fn steel_path_honors(
+    ctx: Context<'_>,
+    #[description = "Adds this reward to the filter, or removes it if it is already in it"]
+    #[autocomplete = autocomplete_honors_reward]
+    reward: Option<String>,
+) -> CmdRet {
+    update_reward_filters(ctx, SubscriptionType::SteelPathHonors, reward).await
+}
+
+/// Choose which Steel Path Circuit Incarnon weapons to be notified about. Leave empty to reset.
+#[command(slash_command)]
+pub async fn incarnons(
+    ctx: Context<'_>,
+    #[description = "Adds this weapon to the filter, or removes it if it is already in it"]
+    weapon: Option<String>,
+) -> CmdRet {
+    update_reward_filters(ctx, SubscriptionType::IncarnonRotation, weapon).await
+}
+
+/// Choose the minimum discount of Darvo deals and flash sales to be notified about.
+#[command(slash_command)]
+pub async fn darvo(
+    ctx: Context<'_>,
+    #[description = "Only notify about offers with at least this discount in percent. Leave empty to reset."]
+    #[min = 0]
+    #[max = 100]
+    minimum_discount: Option<u8>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+
+    let description = match minimum_discount {
+        Some(discount) => {
+            ctx.db()
+                .save_minimum_discount(guild_id, discount.into())
+                .await?;
+            t!(lang, "notifier.minimum_discount_set", discount = discount)
+        },
+        None => {
+            ctx.db().delete_minimum_discount(guild_id).await?;
+            t!(lang, "notifier.minimum_discount_reset").to_owned()
+        },
+    };
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.minimum_discount"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Choose which alert rewards to be notified about. Leave empty to reset.
+#[command(slash_command)]
+pub async fn alerts(
+    ctx: Context<'_>,
+    #[description = "Adds this reward (e.g. Nitain Extract, Forma, Orokin) or removes it if it is already in it"]
+    reward: Option<String>,
+) -> CmdRet {
+    update_reward_filters(ctx, SubscriptionType::Alerts, reward).await
+}
+
+/// Choose which Void Storms to be notified about. Leave all empty to reset.
+#[command(slash_command, rename = "void-storms")]
+pub async fn void_storms(
+    ctx: Context<'_>,
+    #[description = "Only notify about Void Storms of this tier"] tier: Option<FissureTier>,
+    #[description = "Only notify about Void Storms in this Proxima"] region: Option<ProximaRegion>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+    let filter = VoidStormFilter { tier, region };
+
+    let description = if filter.is_empty() {
+        ctx.db().delete_void_storm_filter(guild_id).await?;
+        t!(lang, "notifier.void_storm_filter_reset").to_owned()
+    } else {
+        ctx.db().save_void_storm_filter(guild_id, &filter).await?;
+        format!(
+            "{}\n{}",
+            t!(lang, "notifier.void_storm_filter_set"),
+            describe_void_storm_filter(lang, &filter)
+        )
+    };
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.void_storm_filter_title"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn describe_void_storm_filter(lang: Language, filter: &VoidStormFilter) -> String {
+    filter
+        .tier
+        .map(|tier| {
+            format!(
+                "{}: `{}`",
+                t!(lang, "notifier.void_storm_tier"),
+                tier.name_in(lang)
+            )
+        })
+        .into_iter()
+        .chain(filter.region.map(|region| {
+            format!(
+                "{}: `{}`",
+                t!(lang, "notifier.void_storm_region"),
+                region.name_in(lang)
+            )
+        }))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Choose which bounty rewards of any syndicate to be notified about. Leave empty to reset.
+#[command(slash_command)]
+pub async fn bounties(
+    ctx: Context<'_>,
+    #[description = "Adds this English reward name (e.g. an Arcane) or removes it if it is already in it"]
+    #[autocomplete = autocomplete_english_reward]
+    reward: Option<String>,
+) -> CmdRet {
+    // the notifier fetches the bounties in English, so the filters have to be English as well
+    update_reward_filters(ctx, SubscriptionType::SyndicateBounties, reward).await
+}
+
+/// Choose which kinds of news to be notified about. Leave empty to reset to all news.
+#[command(slash_command)]
+pub async fn news(
+    ctx: Context<'_>,
+    #[description = "Adds this category, or removes it if it is already picked"] category: Option<
+        NewsCategory,
+    >,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+
+    match category {
+        Some(category) => {
+            ctx.db().toggle_news_category(guild_id, category).await?;
+        },
+        None => ctx.db().delete_news_categories(guild_id).await?,
+    }
+
+    let categories = ctx.db().get_news_categories(guild_id).await?;
+    let description = if categories.is_empty() {
+        t!(lang, "notifier.news_categories_reset").to_owned()
+    } else {
+        format!(
+            "{}\n{}",
+            t!(lang, "notifier.news_categories_set"),
+            describe_news_categories(lang, &categories)
+        )
+    };
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.news_categories_title"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn describe_news_categories(lang: Language, categories: &[NewsCategory]) -> String {
+    categories
+        .iter()
+        .map(|category| format!("`{}`", category.name_in(lang)))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn describe_rewards(rewards: &[String]) -> String {
+    rewards
+        .iter()
+        .map(|reward| format!("`{reward}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Toggles `reward` in the reward filters of `subscription_type`, or clears them if it is `None`.
+async fn update_reward_filters(
+    ctx: Context<'_>,
+    subscription_type: SubscriptionType,
+    reward: Option<String>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+    let subscription = subscription_type.name_in(lang);
+
+    match reward {
+        Some(reward) => {
+            ctx.db()
+                .toggle_reward_filter(subscription_type, guild_id, reward.trim())
+                .await?;
+        },
+        None => {
+            ctx.db()
+                .clear_reward_filters(subscription_type, guild_id)
+                .await?;
+        },
+    }
+
+    let description = match ctx
+        .db()
+        .get_reward_filters_for_guild(guild_id)
+        .await?
+        .get(&subscription_type)
+    {
+        Some(rewards) => format!(
+            "{}\n{}",
+            t!(
+                lang,
+                "notifier.reward_filters_set",
+                subscription = subscription
             ),
+            describe_rewards(rewards)
+        ),
+        None => t!(
+            lang,
+            "notifier.reward_filters_reset",
+            subscription = subscription
+        ),
+    };
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "notifier.reward_filters_title"))
+                .description(description),
         ),
     )
     .await?;
@@ -0,0 +1,103 @@
+use poise::serenity_prelude::{self, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{
+    Change,
+    queryable::{DailyDeal, FlashSale},
+};
+
+use crate::{
+    AppData,
+    Error,
+    commands::darvo::{Deal, deal_embed},
+    i18n::{Language, t},
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::DealFilterDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+    },
+};
+
+pub struct DarvoDeals;
+
+impl Notifier for DarvoDeals {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        let callback_data = ListenerCallbackData {
+            ctx,
+            data: data.clone(),
+            tx,
+        };
+
+        // Darvo's deals and the flash sales are separate lists in the worldstate
+        tokio::try_join!(
+            data.worldstate()
+                .call_on_nested_update_with_state::<_, DailyDeal, _>(
+                    daily_deal_callback,
+                    callback_data.clone(),
+                ),
+            data.worldstate()
+                .call_on_nested_update_with_state::<_, FlashSale, _>(
+                    flash_sale_callback,
+                    callback_data,
+                ),
+        )
+        .map(|_| ())
+        .map_err(Error::from)
+    }
+}
+
+async fn daily_deal_callback(data: ListenerCallbackData, deal: &DailyDeal, change: Change) {
+    if change != Change::Added {
+        return;
+    }
+
+    notify(
+        data,
+        |lang| t!(lang, "notifier.darvo.daily_deal"),
+        deal.into(),
+    )
+    .await;
+}
+
+async fn flash_sale_callback(data: ListenerCallbackData, sale: &FlashSale, change: Change) {
+    if change != Change::Added {
+        return;
+    }
+
+    if let Some(deal) = Deal::from_flash_sale(sale) {
+        notify(data, |lang| t!(lang, "notifier.darvo.flash_sale"), deal).await;
+    }
+}
+
+async fn notify(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    title: fn(Language) -> &'static str,
+    deal: Deal,
+) {
+    let minimum_discounts = data
+        .db()
+        .get_all_minimum_discounts()
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::DarvoDeals,
+        |sub| {
+            minimum_discounts
+                .get(&(sub.server_id.get() as i64))
+                .is_none_or(|&minimum| deal.discount >= minimum)
+        },
+        |prefs| deal_embed(prefs, title(prefs.language), &deal).timestamp(Timestamp::now()),
+    )
+    .await
+    .unwrap_or_default();
+}
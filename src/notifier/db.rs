@@ -0,0 +1,624 @@
+use std::{collections::HashMap, future::Future};
+
+use sqlx::SqlitePool;
+
+use crate::{
+    commands::fissures::{FissureTier, ProximaRegion},
+    notifier::{
+        model::{EventMessage, SubscriptionType},
+        news::NewsCategory,
+        void_storms::VoidStormFilter,
+    },
+};
+
+/// Rewards a guild wants to be notified about, for subscriptions that offer rewards.
+///
+/// A guild without reward filters for a subscription is notified about every reward.
+pub trait RewardFilterDbExtension {
+    /// The reward filters of every guild for `subscription_type`, keyed by guild id.
+    fn get_reward_filters(
+        &self,
+        subscription_type: SubscriptionType,
+    ) -> impl Future<Output = Result<HashMap<i64, Vec<String>>, sqlx::Error>> + Send;
+
+    fn get_reward_filters_for_guild(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<HashMap<SubscriptionType, Vec<String>>, sqlx::Error>> + Send;
+
+    /// Adds `reward` to the filters, or removes it if it is already present.
+    ///
+    /// Returns whether the reward is filtered for afterwards.
+    fn toggle_reward_filter(
+        &self,
+        subscription_type: SubscriptionType,
+        guild_id: i64,
+        reward: &str,
+    ) -> impl Future<Output = Result<bool, sqlx::Error>> + Send;
+
+    fn clear_reward_filters(
+        &self,
+        subscription_type: SubscriptionType,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_all_reward_filters(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl RewardFilterDbExtension for SqlitePool {
+    async fn get_reward_filters(
+        &self,
+        subscription_type: SubscriptionType,
+    ) -> Result<HashMap<i64, Vec<String>>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "
+            SELECT server_id, reward
+            FROM reward_filters
+            WHERE subscription_type = $1
+            ORDER BY reward
+            ",
+            subscription_type
+        )
+        .fetch_all(self)
+        .await?;
+
+        let mut filters = HashMap::<i64, Vec<String>>::new();
+        for row in rows {
+            filters.entry(row.server_id).or_default().push(row.reward);
+        }
+
+        Ok(filters)
+    }
+
+    async fn get_reward_filters_for_guild(
+        &self,
+        guild_id: i64,
+    ) -> Result<HashMap<SubscriptionType, Vec<String>>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                subscription_type as "subscription_type: SubscriptionType",
+                reward
+            FROM reward_filters
+            WHERE server_id = $1
+            ORDER BY reward
+            "#,
+            guild_id
+        )
+        .fetch_all(self)
+        .await?;
+
+        let mut filters = HashMap::<SubscriptionType, Vec<String>>::new();
+        for row in rows {
+            filters
+                .entry(row.subscription_type)
+                .or_default()
+                .push(row.reward);
+        }
+
+        Ok(filters)
+    }
+
+    async fn toggle_reward_filter(
+        &self,
+        subscription_type: SubscriptionType,
+        guild_id: i64,
+        reward: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let removed = sqlx::query!(
+            "
+            DELETE FROM reward_filters
+            WHERE server_id = $1 AND subscription_type = $2 AND reward = $3;
+            ",
+            guild_id,
+            subscription_type,
+            reward
+        )
+        .execute(tx.as_mut())
+        .await?
+        .rows_affected()
+            > 0;
+
+        if !removed {
+            sqlx::query!(
+                "
+                INSERT INTO reward_filters (server_id, subscription_type, reward)
+                VALUES ($1, $2, $3);
+                ",
+                guild_id,
+                subscription_type,
+                reward
+            )
+            .execute(tx.as_mut())
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(!removed)
+    }
+
+    async fn clear_reward_filters(
+        &self,
+        subscription_type: SubscriptionType,
+        guild_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM reward_filters
+            WHERE server_id = $1 AND subscription_type = $2;
+            ",
+            guild_id,
+            subscription_type
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_all_reward_filters(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM reward_filters
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// The minimum discount a guild wants to be notified about for Darvo's deals and flash sales.
+pub trait DealFilterDbExtension {
+    fn get_minimum_discount(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Option<i64>, sqlx::Error>> + Send;
+
+    /// The minimum discount of every guild that has set one, keyed by guild id.
+    fn get_all_minimum_discounts(
+        &self,
+    ) -> impl Future<Output = Result<HashMap<i64, i64>, sqlx::Error>> + Send;
+
+    fn save_minimum_discount(
+        &self,
+        guild_id: i64,
+        minimum_discount: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_minimum_discount(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl DealFilterDbExtension for SqlitePool {
+    async fn get_minimum_discount(&self, guild_id: i64) -> Result<Option<i64>, sqlx::Error> {
+        sqlx::query_scalar!(
+            "
+            SELECT minimum_discount
+            FROM deal_filters
+            WHERE server_id = $1
+            ",
+            guild_id
+        )
+        .fetch_optional(self)
+        .await
+    }
+
+    async fn get_all_minimum_discounts(&self) -> Result<HashMap<i64, i64>, sqlx::Error> {
+        let rows = sqlx::query!(
+            "
+            SELECT server_id, minimum_discount
+            FROM deal_filters
+            "
+        )
+        .fetch_all(self)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| (row.server_id, row.minimum_discount))
+            .collect())
+    }
+
+    async fn save_minimum_discount(
+        &self,
+        guild_id: i64,
+        minimum_discount: i64,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            INSERT INTO deal_filters (server_id, minimum_discount)
+            VALUES ($1, $2)
+            ON CONFLICT (server_id)
+            DO UPDATE
+            SET modified_at = CURRENT_TIMESTAMP,
+                minimum_discount = $2;
+            ",
+            guild_id,
+            minimum_discount
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_minimum_discount(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM deal_filters
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+/// The news categories a guild wants to be notified about. No categories means all of them.
+pub trait NewsDbExtension {
+    fn get_news_categories(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Vec<NewsCategory>, sqlx::Error>> + Send;
+
+    /// The news categories of every guild that has picked some, keyed by guild id.
+    fn get_all_news_categories(
+        &self,
+    ) -> impl Future<Output = Result<HashMap<i64, Vec<NewsCategory>>, sqlx::Error>> + Send;
+
+    /// Adds `category`, or removes it if it was picked already.
+    ///
+    /// Returns whether the category is picked afterwards.
+    fn toggle_news_category(
+        &self,
+        guild_id: i64,
+        category: NewsCategory,
+    ) -> impl Future<Output = Result<bool, sqlx::Error>> + Send;
+
+    fn delete_news_categories(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl NewsDbExtension for SqlitePool {
+    async fn get_news_categories(&self, guild_id: i64) -> Result<Vec<NewsCategory>, sqlx::Error> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT category as "category: NewsCategory"
+            FROM news_categories
+            WHERE server_id = $1
+            ORDER BY category
+            "#,
+            guild_id
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn get_all_news_categories(
+        &self,
+    ) -> Result<HashMap<i64, Vec<NewsCategory>>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT server_id, category as "category: NewsCategory"
+            FROM news_categories
+            "#
+        )
+        .fetch_all(self)
+        .await?;
+
+        let mut categories = HashMap::<i64, Vec<NewsCategory>>::new();
+        for row in rows {
+            categories
+                .entry(row.server_id)
+                .or_default()
+                .push(row.category);
+        }
+
+        Ok(categories)
+    }
+
+    async fn toggle_news_category(
+        &self,
+        guild_id: i64,
+        category: NewsCategory,
+    ) -> Result<bool, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let removed = sqlx::query!(
+            "
+            DELETE FROM news_categories
+            WHERE server_id = $1 AND category = $2;
+            ",
+            guild_id,
+            category
+        )
+        .execute(tx.as_mut())
+        .await?
+        .rows_affected()
+            > 0;
+
+        if !removed {
+            sqlx::query!(
+                "
+                INSERT INTO news_categories (server_id, category)
+                VALUES ($1, $2);
+                ",
+                guild_id,
+                category
+            )
+            .execute(tx.as_mut())
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        Ok(!removed)
+    }
+
+    async fn delete_news_categories(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM news_categories
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+pub trait EventMessageDbExtension {
+    fn save_event_message(
+        &self,
+        message: &EventMessage,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn get_event_messages(
+        &self,
+    ) -> impl Future<Output = Result<Vec<EventMessage>, sqlx::Error>> + Send;
+
+    fn delete_event_message(
+        &self,
+        guild_id: i64,
+        event_id: &str,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_all_event_messages(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl EventMessageDbExtension for SqlitePool {
+    async fn save_event_message(&self, message: &EventMessage) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            INSERT INTO event_messages (server_id, event_id, channel_id, message_id)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (server_id, event_id)
+            DO UPDATE
+            SET channel_id = $3,
+                message_id = $4;
+            ",
+            message.server_id,
+            message.event_id,
+            message.channel_id,
+            message.message_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn get_event_messages(&self) -> Result<Vec<EventMessage>, sqlx::Error> {
+        sqlx::query_as!(
+            EventMessage,
+            "
+            SELECT server_id, event_id, channel_id, message_id
+            FROM event_messages
+            "
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn delete_event_message(&self, guild_id: i64, event_id: &str) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM event_messages
+            WHERE server_id = $1 AND event_id = $2;
+            ",
+            guild_id,
+            event_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_all_event_messages(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM event_messages
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+pub trait VoidStormDbExtension {
+    fn get_void_storm_filter(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Option<VoidStormFilter>, sqlx::Error>> + Send;
+
+    /// The Void Storm filter of every guild that has set one, keyed by guild id.
+    fn get_all_void_storm_filters(
+        &self,
+    ) -> impl Future<Output = Result<HashMap<i64, VoidStormFilter>, sqlx::Error>> + Send;
+
+    fn save_void_storm_filter(
+        &self,
+        guild_id: i64,
+        filter: &VoidStormFilter,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_void_storm_filter(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl VoidStormDbExtension for SqlitePool {
+    async fn get_void_storm_filter(
+        &self,
+        guild_id: i64,
+    ) -> Result<Option<VoidStormFilter>, sqlx::Error> {
+        sqlx::query_as!(
+            VoidStormFilter,
+            r#"
+            SELECT
+                tier as "tier: FissureTier",
+                region as "region: ProximaRegion"
+            FROM void_storm_filters
+            WHERE server_id = $1
+            "#,
+            guild_id
+        )
+        .fetch_optional(self)
+        .await
+    }
+
+    async fn get_all_void_storm_filters(
+        &self,
+    ) -> Result<HashMap<i64, VoidStormFilter>, sqlx::Error> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                server_id,
+                tier as "tier: FissureTier",
+                region as "region: ProximaRegion"
+            FROM void_storm_filters
+            "#
+        )
+        .fetch_all(self)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let filter = VoidStormFilter {
+                    tier: row.tier,
+                    region: row.region,
+                };
+                (row.server_id, filter)
+            })
+            .collect())
+    }
+
+    async fn save_void_storm_filter(
+        &self,
+        guild_id: i64,
+        filter: &VoidStormFilter,
+    ) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            INSERT INTO void_storm_filters (server_id, tier, region)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (server_id)
+            DO UPDATE
+            SET modified_at = CURRENT_TIMESTAMP,
+                tier = $2,
+                region = $3;
+            ",
+            guild_id,
+            filter.tier,
+            filter.region
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_void_storm_filter(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM void_storm_filters
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
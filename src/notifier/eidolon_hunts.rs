@@ -1,36 +1,31 @@
-use futures::future::join_all;
-use poise::serenity_prelude::{
-    self,
-    CreateEmbed,
-    CreateMessage,
-    FormattedTimestamp,
-    FormattedTimestampStyle,
-    Mentionable,
-    Timestamp,
-};
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
 use tokio::sync::mpsc::UnboundedSender;
 use warframe::worldstate::{CetusState, TimedEvent, queryable::Cetus};
 
 use crate::{
     AppData,
     Error,
-    notifier::{ListenerCallbackData, Notifier, error::NotifierError, model::SubscriptionType},
-    utils::{self, ApplyIf, DbExtension},
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers,
+    },
+    settings::Preferences,
 };
 
-fn build_embed(cetus: &Cetus) -> CreateEmbed {
-    utils::embed()
-        .title("Eidolon Time!")
-        .description(
-            "Time for Eidolons!\nIt just turned night on Cetus, get ready for some hunting!",
-        )
+fn build_embed(prefs: &Preferences, cetus: &Cetus) -> CreateEmbed {
+    let lang = prefs.language;
+
+    prefs
+        .embed()
+        .title(t!(lang, "notifier.eidolons.title"))
+        .description(t!(lang, "notifier.eidolons.description"))
         .field(
-            "Back to day in",
-            FormattedTimestamp::new(
-                cetus.expiry().into(),
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "notifier.eidolons.back_to_day"),
+            prefs.format_time(cetus.expiry()),
             false,
         )
         .timestamp(Timestamp::now())
@@ -67,37 +62,9 @@ async fn callback(
         return;
     }
 
-    let subscriptions = data
-        .db()
-        .get_subscriptions(SubscriptionType::EidolonHunts)
-        .await
-        .unwrap_or_default();
-
-    let embed = build_embed(cetus);
-
-    let notification_tasks = subscriptions
-        .iter()
-        .map(|sub| async {
-            let result = sub
-                .notification_channel_id
-                .send_message(
-                    &ctx,
-                    CreateMessage::new()
-                        .apply_optionally(sub.role_id_to_mention, |msg, role_id| {
-                            msg.content(role_id.mention().to_string())
-                        })
-                        .add_embed(embed.clone()),
-                )
-                .await;
-
-            if let Err(e) = result {
-                let _ = tx.send(NotifierError {
-                    channel_id: *sub.notification_channel_id,
-                    error: e.into(),
-                });
-            }
-        })
-        .collect::<Vec<_>>();
-
-    join_all(notification_tasks).await;
+    notify_subscribers(&ctx, &data, &tx, SubscriptionType::EidolonHunts, |prefs| {
+        build_embed(prefs, cetus)
+    })
+    .await
+    .unwrap_or_default();
 }
@@ -0,0 +1,240 @@
+use std::time::Duration;
+
+use futures::future::join_all;
+use poise::serenity_prelude::{
+    self,
+    ChannelId,
+    CreateMessage,
+    EditMessage,
+    HttpError,
+    Mentionable,
+    MessageId,
+};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{Change, queryable::Event};
+
+use crate::{
+    AppData,
+    Error,
+    commands::event::event_embed,
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::EventMessageDbExtension,
+        error::NotifierError,
+        model::{EventMessage, SubscriptionType},
+    },
+    settings::Preferences,
+    utils::{ApplyIf, DbExtension},
+};
+
+/// How often the progress shown in the tracked messages is refreshed.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Discord's JSON error codes for a message that can no longer be edited: Unknown Channel,
+/// Unknown Message and Missing Access.
+const GONE_ERROR_CODES: [isize; 3] = [10003, 10008, 50001];
+
+/// Posts a pinned message for every new event and keeps it up to date until the event ends.
+pub struct EventTracker;
+
+impl Notifier for EventTracker {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        let listener = data
+            .worldstate()
+            .call_on_nested_update_with_state::<_, Event, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx: ctx.clone(),
+                    data: data.clone(),
+                    tx,
+                },
+            );
+
+        tokio::try_join!(
+            async { listener.await.map_err(Error::from) },
+            refresh(&ctx, &data)
+        )
+        .map(|_| ())
+    }
+}
+
+async fn callback(data: ListenerCallbackData, event: &Event, change: Change) {
+    match change {
+        Change::Added => announce(data, event).await,
+        Change::Removed => {
+            let ListenerCallbackData { ctx, data, .. } = data;
+            let messages = data.db().get_event_messages().await.unwrap_or_default();
+
+            for message in messages.iter().filter(|msg| msg.event_id == event.id) {
+                finish(&ctx, &data, message, Some(event)).await;
+            }
+        },
+    }
+}
+
+async fn announce(ListenerCallbackData { ctx, data, tx }: ListenerCallbackData, event: &Event) {
+    let Ok(subscriptions) = data.db().get_subscriptions(SubscriptionType::Events).await else {
+        return;
+    };
+
+    let tasks = subscriptions.iter().map(|sub| async {
+        let server_id = sub.server_id.get() as i64;
+        let prefs = Preferences::for_guild(data.db(), server_id)
+            .await
+            .unwrap_or_default();
+
+        let result = async {
+            let message = sub
+                .notification_channel_id
+                .send_message(
+                    &ctx,
+                    CreateMessage::new()
+                        .apply_optionally(sub.role_id_to_mention, |msg, role_id| {
+                            msg.content(role_id.mention().to_string())
+                        })
+                        .add_embed(event_embed(&prefs, event)),
+                )
+                .await?;
+
+            // pinning needs Manage Messages, which is optional for the subscription to work
+            if let Err(e) = message.pin(&ctx).await {
+                tracing::warn!(error = %e, "Could not pin event message");
+            }
+
+            data.db()
+                .save_event_message(&EventMessage {
+                    server_id,
+                    event_id: event.id.clone(),
+                    channel_id: message.channel_id.get() as i64,
+                    message_id: message.id.get() as i64,
+                })
+                .await?;
+
+            Ok::<_, Error>(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            let _ = tx.send(NotifierError::new(*sub.notification_channel_id, e));
+        }
+    });
+
+    join_all(tasks).await;
+}
+
+/// Updates every tracked message with the current state of its event.
+///
+/// Events that ended while the bot was offline are marked finished here as well.
+async fn refresh(ctx: &serenity_prelude::Context, data: &AppData) -> Result<(), Error> {
+    loop {
+        tokio::time::sleep(REFRESH_INTERVAL).await;
+
+        let events = match data.worldstate().fetch::<Event>().await {
+            Ok(events) => events,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not fetch events");
+                continue;
+            },
+        };
+
+        let messages = match data.db().get_event_messages().await {
+            Ok(messages) => messages,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not load tracked event messages");
+                continue;
+            },
+        };
+
+        for message in messages {
+            match events.iter().find(|event| event.id == message.event_id) {
+                Some(event) => update(ctx, data, &message, event).await,
+                None => finish(ctx, data, &message, None).await,
+            }
+        }
+    }
+}
+
+async fn update(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    message: &EventMessage,
+    event: &Event,
+) {
+    let prefs = Preferences::for_guild(data.db(), message.server_id)
+        .await
+        .unwrap_or_default();
+
+    let result = ChannelId::new(message.channel_id as u64)
+        .edit_message(
+            ctx,
+            MessageId::new(message.message_id as u64),
+            EditMessage::new().embed(event_embed(&prefs, event)),
+        )
+        .await;
+
+    let Err(e) = result else {
+        return;
+    };
+
+    tracing::warn!(error = %e, "Could not update event message");
+
+    // anything else is likely temporary, so the next refresh tries again
+    if is_gone(&e) {
+        data.db()
+            .delete_event_message(message.server_id, &message.event_id)
+            .await
+            .unwrap_or_default();
+    }
+}
+
+/// Whether `error` means the message or its channel was deleted or can no longer be accessed.
+fn is_gone(error: &serenity_prelude::Error) -> bool {
+    match error {
+        serenity_prelude::Error::Http(HttpError::UnsuccessfulRequest(response)) => {
+            GONE_ERROR_CODES.contains(&response.error.code)
+        },
+        _ => false,
+    }
+}
+
+/// Marks the message finished, unpins it and stops tracking it.
+///
+/// The embed is updated one last time if the final state of the event is known.
+async fn finish(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    message: &EventMessage,
+    event: Option<&Event>,
+) {
+    let prefs = Preferences::for_guild(data.db(), message.server_id)
+        .await
+        .unwrap_or_default();
+    let channel_id = ChannelId::new(message.channel_id as u64);
+    let message_id = MessageId::new(message.message_id as u64);
+
+    let edit = EditMessage::new()
+        .content(format!(
+            "**{}**",
+            t!(prefs.language, "notifier.events.finished")
+        ))
+        .apply_optionally(event, |edit, event| edit.embed(event_embed(&prefs, event)));
+
+    if let Err(e) = channel_id.edit_message(ctx, message_id, edit).await {
+        tracing::warn!(error = %e, "Could not mark event message finished");
+    }
+
+    if let Err(e) = channel_id.unpin(ctx, message_id).await {
+        tracing::warn!(error = %e, "Could not unpin event message");
+    }
+
+    data.db()
+        .delete_event_message(message.server_id, &message.event_id)
+        .await
+        .unwrap_or_default();
+}
@@ -0,0 +1,98 @@
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::queryable::DuviriCycle;
+
+use crate::{
+    AppData,
+    Error,
+    commands::duviri::circuit_incarnons,
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::RewardFilterDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+        wants_reward,
+    },
+    settings::Preferences,
+};
+
+fn build_embed(prefs: &Preferences, incarnons: &[String]) -> CreateEmbed {
+    let lang = prefs.language;
+
+    prefs
+        .embed()
+        .title(t!(lang, "notifier.incarnons.title"))
+        .description(
+            incarnons
+                .iter()
+                .map(|incarnon| format!("- {incarnon}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        )
+        .timestamp(Timestamp::now())
+}
+
+pub struct IncarnonRotation;
+
+impl Notifier for IncarnonRotation {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_update_with_state::<_, DuviriCycle, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    before: &DuviriCycle,
+    cycle: &DuviriCycle,
+) {
+    // the cycle also updates whenever the spiral mood changes
+    let previous = circuit_incarnons(before);
+    let new_incarnons = circuit_incarnons(cycle)
+        .iter()
+        .filter(|incarnon| !previous.contains(incarnon))
+        .collect::<Vec<_>>();
+
+    if new_incarnons.is_empty() {
+        return;
+    }
+
+    let filters = data
+        .db()
+        .get_reward_filters(SubscriptionType::IncarnonRotation)
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::IncarnonRotation,
+        |sub| {
+            wants_reward(
+                &filters,
+                sub,
+                new_incarnons.iter().map(|name| name.as_str()),
+            )
+        },
+        |prefs| build_embed(prefs, circuit_incarnons(cycle)),
+    )
+    .await
+    .unwrap_or_default();
+}
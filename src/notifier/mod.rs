@@ -1,24 +1,47 @@
+pub mod alerts;
+pub mod arbitrations;
 pub mod commands;
+pub mod darvo_deals;
+pub mod db;
 pub mod eidolon_hunts;
 pub mod error;
+pub mod events;
+pub mod incarnon_rotation;
 pub mod model;
-pub mod s_tier_arbitrations;
+pub mod news;
+pub mod nightwave;
 pub mod sp_disruption_fissure;
+pub mod steel_path_honors;
+pub mod syndicate_bounties;
+pub mod void_storms;
 
-use std::{fmt::Debug, future::Future};
+use std::{collections::HashMap, fmt::Debug, future::Future};
 
-use poise::serenity_prelude::{self};
+use futures::future::join_all;
+use poise::serenity_prelude::{self, CreateEmbed, CreateMessage, Mentionable};
 use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
 
 use crate::{
     AppData,
     Error,
     notifier::{
+        alerts::Alerts,
+        arbitrations::ArbitrationScheduler,
+        darvo_deals::DarvoDeals,
         eidolon_hunts::EidolonHunts,
         error::{NotifierError, handle_notifier_error},
-        s_tier_arbitrations::STierArbitrationListener,
+        events::EventTracker,
+        incarnon_rotation::IncarnonRotation,
+        model::{ServerSubscription, SubscriptionType},
+        news::WarframeNews,
+        nightwave::NightwaveWeeklies,
         sp_disruption_fissure::SteelPathDisruptionFissures,
+        steel_path_honors::SteelPathHonors,
+        syndicate_bounties::SyndicateBounties,
+        void_storms::VoidStorms,
     },
+    settings::Preferences,
+    utils::{ApplyIf, DbExtension},
 };
 
 #[derive(Debug, Clone)]
@@ -37,12 +60,30 @@ pub trait Notifier {
 }
 
 pub async fn setup(ctx: serenity_prelude::Context, data: AppData) -> Result<(), Error> {
-    spawn_notifier::<STierArbitrationListener>(&ctx, &data)?;
+    spawn_notifier::<ArbitrationScheduler>(&ctx, &data)?;
 
     spawn_notifier::<SteelPathDisruptionFissures>(&ctx, &data)?;
 
     spawn_notifier::<EidolonHunts>(&ctx, &data)?;
 
+    spawn_notifier::<SteelPathHonors>(&ctx, &data)?;
+
+    spawn_notifier::<NightwaveWeeklies>(&ctx, &data)?;
+
+    spawn_notifier::<IncarnonRotation>(&ctx, &data)?;
+
+    spawn_notifier::<DarvoDeals>(&ctx, &data)?;
+
+    spawn_notifier::<WarframeNews>(&ctx, &data)?;
+
+    spawn_notifier::<EventTracker>(&ctx, &data)?;
+
+    spawn_notifier::<Alerts>(&ctx, &data)?;
+
+    spawn_notifier::<VoidStorms>(&ctx, &data)?;
+
+    spawn_notifier::<SyndicateBounties>(&ctx, &data)?;
+
     Ok(())
 }
 
@@ -64,3 +105,83 @@ where
 
     Ok(())
 }
+
+/// Sends a notification to every server subscribed to `subscription_type`.
+///
+/// The embed is built separately for each server, so that it uses the server's settings.
+async fn notify_subscribers(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    tx: &UnboundedSender<NotifierError>,
+    subscription_type: SubscriptionType,
+    build_embed: impl Fn(&Preferences) -> CreateEmbed,
+) -> Result<(), sqlx::Error> {
+    notify_subscribers_where(ctx, data, tx, subscription_type, |_| true, build_embed).await
+}
+
+/// Like [`notify_subscribers`], but skips every subscription `should_notify` rejects.
+async fn notify_subscribers_where(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    tx: &UnboundedSender<NotifierError>,
+    subscription_type: SubscriptionType,
+    should_notify: impl Fn(&ServerSubscription) -> bool,
+    build_embed: impl Fn(&Preferences) -> CreateEmbed,
+) -> Result<(), sqlx::Error> {
+    let subscriptions = data.db().get_subscriptions(subscription_type).await?;
+
+    let notification_tasks = subscriptions
+        .iter()
+        .filter(|sub| should_notify(sub))
+        .map(|sub| async {
+            let prefs = Preferences::for_guild(data.db(), sub.server_id.get() as i64)
+                .await
+                .unwrap_or_default();
+
+            let result = sub
+                .notification_channel_id
+                .send_message(
+                    ctx,
+                    CreateMessage::new()
+                        .apply_optionally(sub.role_id_to_mention, |msg, role_id| {
+                            msg.content(role_id.mention().to_string())
+                        })
+                        .add_embed(build_embed(&prefs)),
+                )
+                .await;
+
+            if let Err(e) = result {
+                let _ = tx.send(NotifierError {
+                    channel_id: *sub.notification_channel_id,
+                    error: e.into(),
+                });
+            }
+        })
+        .collect::<Vec<_>>();
+
+    join_all(notification_tasks).await;
+
+    Ok(())
+}
+
+/// Whether `sub` should be notified about a notification offering `rewards`, given the
+/// reward filters of every guild as returned by
+/// [`RewardFilterDbExtension::get_reward_filters`](db::RewardFilterDbExtension::get_reward_filters).
+///
+/// A filter matches any reward containing it, ignoring case.
+fn wants_reward<'a>(
+    filters: &HashMap<i64, Vec<String>>,
+    sub: &ServerSubscription,
+    rewards: impl IntoIterator<Item = &'a str>,
+) -> bool {
+    let Some(filters) = filters.get(&(sub.server_id.get() as i64)) else {
+        return true;
+    };
+
+    rewards.into_iter().any(|reward| {
+        let reward = reward.to_lowercase();
+        filters
+            .iter()
+            .any(|filter| reward.contains(&filter.to_lowercase()))
+    })
+}
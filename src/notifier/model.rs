@@ -6,18 +6,69 @@ use poise::serenity_prelude::{ChannelId, GuildId, RoleId};
 use sqlx::{Decode, Sqlite, error::BoxDynError};
 
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, sqlx::Type, poise::ChoiceParameter,
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, sqlx::Type, poise::ChoiceParameter,
 )]
 #[repr(i64)]
 pub enum SubscriptionType {
-    #[name = "S-Tier Arbitrations"]
-    STierArbitrations,
+    #[name = "Arbitrations"]
+    #[name_localized("de", "Schiedsgerichte")]
+    #[name_localized("fr", "Arbitrages")]
+    Arbitrations,
 
     #[name = "Steel Path Disruption Fissures"]
+    #[name_localized("de", "Stahlpfad-Störungsrisse")]
+    #[name_localized("fr", "Fissures Perturbation de la Voie d'Acier")]
     SteelPathDisruptionFissures,
 
     #[name = "Eidolon Hunts"]
+    #[name_localized("de", "Eidolon-Jagden")]
+    #[name_localized("fr", "Chasses aux Eidolons")]
     EidolonHunts,
+
+    #[name = "Steel Path Honors"]
+    #[name_localized("de", "Stahlpfad-Ehrungen")]
+    #[name_localized("fr", "Honneurs de la Voie d'Acier")]
+    SteelPathHonors,
+
+    #[name = "Nightwave Weekly Acts"]
+    #[name_localized("de", "Wöchentliche Nightwave-Akte")]
+    #[name_localized("fr", "Actes hebdomadaires Nightwave")]
+    NightwaveWeeklies,
+
+    #[name = "Steel Path Circuit Incarnons"]
+    #[name_localized("de", "Stahlpfad-Zirkel-Inkarnons")]
+    #[name_localized("fr", "Incarnons du Circuit de la Voie d'Acier")]
+    IncarnonRotation,
+
+    #[name = "Darvo Deals & Flash Sales"]
+    #[name_localized("de", "Darvos Angebote & Blitzangebote")]
+    #[name_localized("fr", "Offres de Darvo & Ventes flash")]
+    DarvoDeals,
+
+    #[name = "Warframe News"]
+    #[name_localized("de", "Warframe-Neuigkeiten")]
+    #[name_localized("fr", "Actualités Warframe")]
+    News,
+
+    #[name = "Events"]
+    #[name_localized("de", "Events")]
+    #[name_localized("fr", "Événements")]
+    Events,
+
+    #[name = "Alerts"]
+    #[name_localized("de", "Alarme")]
+    #[name_localized("fr", "Alertes")]
+    Alerts,
+
+    #[name = "Void Storms"]
+    #[name_localized("de", "Void-Stürme")]
+    #[name_localized("fr", "Tempêtes du Néant")]
+    VoidStorms,
+
+    #[name = "Syndicate Bounties"]
+    #[name_localized("de", "Syndikat-Kopfgelder")]
+    #[name_localized("fr", "Primes des Syndicats")]
+    SyndicateBounties,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Display)]
@@ -81,3 +132,12 @@ pub struct ServerSubscription {
     pub modified_at: Option<chrono::DateTime<Utc>>,
     pub role_id_to_mention: Option<RoleIdToMention>,
 }
+
+/// The pinned message tracking an event in a guild.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventMessage {
+    pub server_id: i64,
+    pub event_id: String,
+    pub channel_id: i64,
+    pub message_id: i64,
+}
@@ -0,0 +1,136 @@
+use poise::{
+    ChoiceParameter,
+    serenity_prelude::{self, CreateEmbed, Timestamp},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{Change, queryable::News};
+
+use crate::{
+    AppData,
+    Error,
+    i18n::{LocalizedChoice, t},
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::NewsDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+    },
+    settings::Preferences,
+};
+
+/// The kinds of news items a guild can pick from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, sqlx::Type, ChoiceParameter)]
+#[repr(i64)]
+pub enum NewsCategory {
+    #[name = "Updates"]
+    #[name_localized("de", "Updates")]
+    #[name_localized("fr", "Mises à jour")]
+    Updates,
+
+    #[name = "Prime Access"]
+    #[name_localized("de", "Prime Access")]
+    #[name_localized("fr", "Prime Access")]
+    PrimeAccess,
+
+    #[name = "Streams"]
+    #[name_localized("de", "Streams")]
+    #[name_localized("fr", "Streams")]
+    Streams,
+
+    #[name = "Other (Dev Workshops, Events, ...)"]
+    #[name_localized("de", "Sonstiges (Dev Workshops, Events, ...)")]
+    #[name_localized("fr", "Autres (Dev Workshops, événements, ...)")]
+    Other,
+}
+
+impl NewsCategory {
+    pub fn of(news: &News) -> Self {
+        if news.update {
+            Self::Updates
+        } else if news.prime_access {
+            Self::PrimeAccess
+        } else if news.stream {
+            Self::Streams
+        } else {
+            Self::Other
+        }
+    }
+}
+
+fn build_embed(prefs: &Preferences, news: &News) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let embed = prefs
+        .embed()
+        .title(&news.message)
+        .url(&news.link)
+        .description(format!(
+            "{}: `{}`",
+            t!(lang, "notifier.news.category"),
+            NewsCategory::of(news).name_in(lang)
+        ))
+        .timestamp(Timestamp::from(news.date));
+
+    if news.image_link.is_empty() {
+        embed
+    } else {
+        embed.image(&news.image_link)
+    }
+}
+
+pub struct WarframeNews;
+
+impl Notifier for WarframeNews {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_nested_update_with_state::<_, News, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    news: &News,
+    change: Change,
+) {
+    if change != Change::Added {
+        return;
+    }
+
+    let category = NewsCategory::of(news);
+    let categories = data
+        .db()
+        .get_all_news_categories()
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::News,
+        // guilds that did not pick any categories get every news item
+        |sub| {
+            categories
+                .get(&(sub.server_id.get() as i64))
+                .is_none_or(|categories| categories.contains(&category))
+        },
+        |prefs| build_embed(prefs, news),
+    )
+    .await
+    .unwrap_or_default();
+}
@@ -0,0 +1,82 @@
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::queryable::{Nightwave, NightwaveChallenge};
+
+use crate::{
+    AppData,
+    Error,
+    commands::nightwave::{NightwaveCategory, add_challenge_fields},
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers,
+    },
+    settings::Preferences,
+};
+
+fn build_embed(prefs: &Preferences, challenges: &[&NightwaveChallenge]) -> CreateEmbed {
+    let embed = prefs
+        .embed()
+        .title(t!(prefs.language, "notifier.nightwave.title"))
+        .timestamp(Timestamp::now());
+
+    add_challenge_fields(prefs, embed, challenges.iter().copied())
+}
+
+pub struct NightwaveWeeklies;
+
+impl Notifier for NightwaveWeeklies {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_update_with_state::<_, Nightwave, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    before: &Nightwave,
+    nightwave: &Nightwave,
+) {
+    // the daily acts change every day, only announce once the weekly ones were replaced
+    let new_weeklies = nightwave
+        .active_challenges
+        .iter()
+        .filter(|challenge| NightwaveCategory::of(challenge) != NightwaveCategory::Daily)
+        .filter(|challenge| {
+            !before
+                .active_challenges
+                .iter()
+                .any(|old| old.id == challenge.id)
+        })
+        .collect::<Vec<_>>();
+
+    if new_weeklies.is_empty() {
+        return;
+    }
+
+    notify_subscribers(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::NightwaveWeeklies,
+        |prefs| build_embed(prefs, &new_weeklies),
+    )
+    .await
+    .unwrap_or_default();
+}
@@ -1,36 +1,33 @@
-use futures::future::join_all;
-use poise::serenity_prelude::{
-    self,
-    CreateEmbed,
-    CreateMessage,
-    FormattedTimestamp,
-    FormattedTimestampStyle,
-    Mentionable,
-    Timestamp,
-};
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
 use tokio::sync::mpsc::UnboundedSender;
 use warframe::worldstate::{Change, MissionType, Tier, TimedEvent, queryable::Fissure};
 
 use crate::{
     AppData,
     Error,
-    notifier::{ListenerCallbackData, Notifier, error::NotifierError, model::SubscriptionType},
-    utils::{self, ApplyIf, DbExtension},
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers,
+    },
+    settings::Preferences,
 };
 
-fn build_embed(fissure: &Fissure) -> CreateEmbed {
-    utils::embed()
-        .title("New Steel Path Disruption Fissure")
-        .field("Node", &fissure.node, true)
-        .field("Mission Type", &fissure.mission_type, true)
-        .field("Tier", fissure.tier.to_string(), true)
+fn build_embed(prefs: &Preferences, fissure: &Fissure) -> CreateEmbed {
+    let lang = prefs.language;
+
+    prefs
+        .embed()
+        .title(t!(lang, "notifier.sp_disruption.title"))
+        .field(t!(lang, "arbi.node"), &fissure.node, true)
+        .field(t!(lang, "arbi.mission_type"), &fissure.mission_type, true)
+        .field(t!(lang, "arbi.tier"), fissure.tier.to_string(), true)
         .field(
-            "Ends",
-            FormattedTimestamp::new(
-                fissure.expiry().into(),
-                Some(FormattedTimestampStyle::RelativeTime),
-            )
-            .to_string(),
+            t!(lang, "common.ends"),
+            prefs.format_time(fissure.expiry()),
             false,
         )
         .timestamp(Timestamp::now())
@@ -67,39 +64,15 @@ async fn callback(
         return;
     }
 
-    let subscriptions = data
-        .db()
-        .get_subscriptions(SubscriptionType::SteelPathDisruptionFissures)
-        .await
-        .unwrap_or_default();
-
-    let embed = build_embed(fissure);
-
-    let notification_tasks = subscriptions
-        .iter()
-        .map(|sub| async {
-            let result = sub
-                .notification_channel_id
-                .send_message(
-                    &ctx,
-                    CreateMessage::new()
-                        .apply_optionally(sub.role_id_to_mention, |msg, role_id| {
-                            msg.content(role_id.mention().to_string())
-                        })
-                        .add_embed(embed.clone()),
-                )
-                .await;
-
-            if let Err(e) = result {
-                let _ = tx.send(NotifierError {
-                    channel_id: *sub.notification_channel_id,
-                    error: e.into(),
-                });
-            }
-        })
-        .collect::<Vec<_>>();
-
-    join_all(notification_tasks).await;
+    notify_subscribers(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::SteelPathDisruptionFissures,
+        |prefs| build_embed(prefs, fissure),
+    )
+    .await
+    .unwrap_or_default();
 }
 
 /// Checks if a Steel Path Disruption Fissure is valid for notification.
@@ -0,0 +1,88 @@
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{TimedEvent, queryable::SteelPath};
+
+use crate::{
+    AppData,
+    Error,
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::RewardFilterDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+        wants_reward,
+    },
+    settings::Preferences,
+};
+
+fn build_embed(prefs: &Preferences, steel_path: &SteelPath) -> CreateEmbed {
+    let lang = prefs.language;
+
+    prefs
+        .embed()
+        .title(t!(lang, "notifier.steel_path.title"))
+        .description(t!(
+            lang,
+            "steel_path.reward_cost",
+            reward = steel_path.current_reward.name,
+            cost = steel_path.current_reward.cost
+        ))
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(steel_path.expiry()),
+            false,
+        )
+        .timestamp(Timestamp::now())
+}
+
+pub struct SteelPathHonors;
+
+impl Notifier for SteelPathHonors {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_update_with_state::<_, SteelPath, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    before: &SteelPath,
+    steel_path: &SteelPath,
+) {
+    if before.current_reward.name == steel_path.current_reward.name {
+        return;
+    }
+
+    let filters = data
+        .db()
+        .get_reward_filters(SubscriptionType::SteelPathHonors)
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::SteelPathHonors,
+        |sub| wants_reward(&filters, sub, [steel_path.current_reward.name.as_str()]),
+        |prefs| build_embed(prefs, steel_path),
+    )
+    .await
+    .unwrap_or_default();
+}
@@ -0,0 +1,116 @@
+use itertools::Itertools;
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{Change, SyndicateMission, TimedEvent};
+
+use crate::{
+    AppData,
+    Error,
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::RewardFilterDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+        wants_reward,
+    },
+    settings::Preferences,
+};
+
+fn build_embed(prefs: &Preferences, mission: &SyndicateMission) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let embed = prefs
+        .embed()
+        .title(t!(
+            lang,
+            "notifier.bounties.title",
+            syndicate = mission.syndicate
+        ))
+        .timestamp(Timestamp::now());
+
+    mission
+        .jobs
+        .iter()
+        .fold(embed, |embed, job| {
+            embed.field(
+                format!(
+                    "{}: {}",
+                    t!(lang, "worldstate.enemy_levels"),
+                    job.enemy_levels.iter().join(" - ")
+                ),
+                job.reward_pool
+                    .iter()
+                    .map(|reward| format!("- {reward}"))
+                    .join("\n"),
+                true,
+            )
+        })
+        .field(
+            t!(lang, "common.ends"),
+            prefs.format_time(mission.expiry()),
+            false,
+        )
+}
+
+/// Announces new bounty rotations of every syndicate that offers bounties.
+pub struct SyndicateBounties;
+
+impl Notifier for SyndicateBounties {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_nested_update_with_state::<_, SyndicateMission, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    mission: &SyndicateMission,
+    change: Change,
+) {
+    // syndicates like Steel Meridian only have missions, but no bounties
+    if change != Change::Added || mission.jobs.is_empty() {
+        return;
+    }
+
+    let filters = data
+        .db()
+        .get_reward_filters(SubscriptionType::SyndicateBounties)
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::SyndicateBounties,
+        |sub| {
+            wants_reward(
+                &filters,
+                sub,
+                mission
+                    .jobs
+                    .iter()
+                    .flat_map(|job| job.reward_pool.iter().map(String::as_str)),
+            )
+        },
+        |prefs| build_embed(prefs, mission),
+    )
+    .await
+    .unwrap_or_default();
+}
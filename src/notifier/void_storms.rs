@@ -0,0 +1,104 @@
+use poise::serenity_prelude::{self, CreateEmbed, Timestamp};
+use tokio::sync::mpsc::UnboundedSender;
+use warframe::worldstate::{Change, queryable::Fissure};
+
+use crate::{
+    AppData,
+    Error,
+    commands::fissures::{FissureTier, ProximaRegion, add_fissure_fields},
+    i18n::t,
+    notifier::{
+        ListenerCallbackData,
+        Notifier,
+        db::VoidStormDbExtension,
+        error::NotifierError,
+        model::SubscriptionType,
+        notify_subscribers_where,
+    },
+    settings::Preferences,
+};
+
+/// Which Void Storms a guild is notified about. Unset fields match every Void Storm.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VoidStormFilter {
+    pub tier: Option<FissureTier>,
+    pub region: Option<ProximaRegion>,
+}
+
+impl VoidStormFilter {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    pub fn matches(&self, fissure: &Fissure) -> bool {
+        self.tier
+            .is_none_or(|tier| FissureTier::from(&fissure.tier) == tier)
+            && self
+                .region
+                .is_none_or(|region| ProximaRegion::of(fissure) == Some(region))
+    }
+}
+
+fn build_embed(prefs: &Preferences, fissure: &Fissure) -> CreateEmbed {
+    let lang = prefs.language;
+
+    let embed = prefs
+        .embed()
+        .title(t!(lang, "notifier.void_storm.title", tier = fissure.tier))
+        .timestamp(Timestamp::now());
+
+    add_fissure_fields(prefs, embed, fissure)
+}
+
+pub struct VoidStorms;
+
+impl Notifier for VoidStorms {
+    async fn run(
+        ctx: serenity_prelude::Context,
+        data: AppData,
+        tx: UnboundedSender<NotifierError>,
+    ) -> Result<(), Error> {
+        data.worldstate()
+            .call_on_nested_update_with_state::<_, Fissure, _>(
+                callback,
+                ListenerCallbackData {
+                    ctx,
+                    data: data.clone(),
+                    tx,
+                },
+            )
+            .await
+            .map_err(Error::from)
+    }
+}
+
+async fn callback(
+    ListenerCallbackData { ctx, data, tx }: ListenerCallbackData,
+    fissure: &Fissure,
+    change: Change,
+) {
+    if change != Change::Added || !fissure.is_storm {
+        return;
+    }
+
+    let filters = data
+        .db()
+        .get_all_void_storm_filters()
+        .await
+        .unwrap_or_default();
+
+    notify_subscribers_where(
+        &ctx,
+        &data,
+        &tx,
+        SubscriptionType::VoidStorms,
+        |sub| {
+            filters
+                .get(&(sub.server_id.get() as i64))
+                .is_none_or(|filter| filter.matches(fissure))
+        },
+        |prefs| build_embed(prefs, fissure),
+    )
+    .await
+    .unwrap_or_default();
+}
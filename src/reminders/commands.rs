@@ -0,0 +1,348 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use poise::{CreateReply, command, serenity_prelude::AutocompleteChoice};
+use warframe::worldstate::{
+    TimedEvent,
+    queryable::{ArchonHunt, Cetus, VoidTrader},
+};
+
+use crate::{
+    CmdRet,
+    Context,
+    commands::eidolons::night_starts,
+    i18n::t,
+    reminders::{Reminder, ReminderEvent, db::ReminderDbExtension},
+    utils::ContextExt,
+};
+
+const DEFAULT_MINUTES_BEFORE: u32 = 15;
+
+/// The most reminders a user can have at once, which is also what fits into an autocomplete.
+const MAX_REMINDERS: usize = 25;
+
+/// Autocompletes the upcoming Arbitrations, as listed by `/upcoming-arbitrations`.
+async fn autocomplete_arbitration(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let prefs = ctx.preferences().await.unwrap_or_default();
+    let lang = prefs.language;
+    let game_data = ctx.data().game_data();
+    let partial = partial.to_lowercase();
+
+    game_data
+        .localized_arbi_data(lang)
+        .iter_upcoming()
+        .map(|(key, arbi)| {
+            let name = t!(
+                lang,
+                "remind.arbitration_choice",
+                tier = arbi.tier,
+                node = arbi.node,
+                mission_type = arbi.mission_type,
+                time = prefs.plain_time(arbi.activation)
+            );
+            (name, *key)
+        })
+        .filter(|(name, _)| name.to_lowercase().contains(&partial))
+        .take(25)
+        .map(|(name, key)| AutocompleteChoice::new(name, key))
+        .collect()
+}
+
+/// Autocompletes the author's reminders.
+async fn autocomplete_reminder(ctx: Context<'_>, partial: &str) -> Vec<AutocompleteChoice> {
+    let prefs = ctx.preferences().await.unwrap_or_default();
+    let lang = prefs.language;
+    let partial = partial.to_lowercase();
+
+    ctx.db()
+        .get_reminders_for_user(ctx.author().id.get() as i64)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|reminder| {
+            let name = t!(
+                lang,
+                "remind.reminder_choice",
+                id = reminder.id,
+                event = reminder.describe(lang),
+                time = prefs.plain_time(reminder.event_at)
+            );
+            (name, reminder.id)
+        })
+        .filter(|(name, _)| name.to_lowercase().contains(&partial))
+        .map(|(name, id)| AutocompleteChoice::new(name, id))
+        .collect()
+}
+
+/// Stores a reminder `minutes_before` the event and confirms it.
+async fn set_reminder(
+    ctx: Context<'_>,
+    event: ReminderEvent,
+    detail: Option<String>,
+    event_at: DateTime<Utc>,
+    minutes_before: Option<u32>,
+    in_channel: Option<bool>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let user_id = ctx.author().id.get() as i64;
+
+    let minutes_before = minutes_before.unwrap_or(DEFAULT_MINUTES_BEFORE);
+    let remind_at = event_at - TimeDelta::minutes(minutes_before as i64);
+
+    if remind_at <= Utc::now() {
+        return Err(t!(lang, "remind.too_late", time = prefs.format_time(event_at)).into());
+    }
+
+    if ctx.db().get_reminders_for_user(user_id).await?.len() >= MAX_REMINDERS {
+        return Err(t!(lang, "remind.limit_reached", max = MAX_REMINDERS).into());
+    }
+
+    let channel_id = match (in_channel.unwrap_or(false), ctx.guild_id()) {
+        (false, _) => None,
+        (true, Some(_)) => Some(ctx.channel_id().get() as i64),
+        (true, None) => return Err(t!(lang, "remind.channel_outside_guild").into()),
+    };
+
+    let mut reminder = Reminder {
+        id: 0,
+        user_id,
+        server_id: ctx.guild_id().map(|id| id.get() as i64),
+        channel_id,
+        event,
+        detail,
+        event_at,
+        remind_at,
+    };
+
+    reminder.id = ctx.db().save_reminder(&reminder).await?;
+
+    ctx.send(
+        CreateReply::default()
+            .content(t!(
+                lang,
+                "remind.set",
+                event = reminder.describe(lang),
+                time = prefs.format_time(remind_at),
+                delivery = reminder.delivery(lang)
+            ))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[command(
+    slash_command,
+    subcommands("arbitration", "cetus_night", "baro", "archon_reset", "list", "cancel"),
+    subcommand_required
+)]
+pub async fn remind(_: Context<'_>) -> CmdRet {
+    Ok(())
+}
+
+/// Reminds you before an upcoming Arbitration
+#[command(slash_command)]
+pub async fn arbitration(
+    ctx: Context<'_>,
+    #[description = "The Arbitration to be reminded of"]
+    #[autocomplete = autocomplete_arbitration]
+    arbitration: i64,
+    #[description = "How many minutes before it starts to remind you (default: 15)"]
+    #[max = 1440]
+    minutes_before: Option<u32>,
+    #[description = "Ping you in this channel instead of sending a DM"] in_channel: Option<bool>,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+    let game_data = ctx.data().game_data();
+
+    let (_, arbi) = game_data
+        .localized_arbi_data(lang)
+        .iter_upcoming()
+        .find(|(key, _)| **key == arbitration)
+        .ok_or(t!(lang, "remind.unknown_arbitration"))?;
+
+    let detail = t!(
+        lang,
+        "remind.arbitration_detail",
+        tier = arbi.tier,
+        node = arbi.node,
+        mission_type = arbi.mission_type
+    );
+
+    set_reminder(
+        ctx,
+        ReminderEvent::Arbitration,
+        Some(detail),
+        arbi.activation,
+        minutes_before,
+        in_channel,
+    )
+    .await
+}
+
+/// Reminds you before the next night on the Plains of Eidolon
+#[command(slash_command, rename = "cetus-night")]
+pub async fn cetus_night(
+    ctx: Context<'_>,
+    #[description = "How many minutes before it starts to remind you (default: 15)"]
+    #[max = 1440]
+    minutes_before: Option<u32>,
+    #[description = "Ping you in this channel instead of sending a DM"] in_channel: Option<bool>,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+    let cetus = ctx.data().worldstate().fetch::<Cetus>().await?;
+    let now = Utc::now();
+
+    // the first start is that of the current night, if it is night right now
+    let night = night_starts(&cetus)
+        .find(|start| *start > now)
+        .ok_or(t!(lang, "remind.no_night"))?;
+
+    set_reminder(
+        ctx,
+        ReminderEvent::CetusNight,
+        None,
+        night,
+        minutes_before,
+        in_channel,
+    )
+    .await
+}
+
+/// Reminds you before Baro Ki'Teer arrives
+#[command(slash_command)]
+pub async fn baro(
+    ctx: Context<'_>,
+    #[description = "How many minutes before he arrives to remind you (default: 15)"]
+    #[max = 1440]
+    minutes_before: Option<u32>,
+    #[description = "Ping you in this channel instead of sending a DM"] in_channel: Option<bool>,
+) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+    let trader = ctx
+        .data()
+        .worldstate()
+        .fetch_using_lang::<VoidTrader>(lang.into())
+        .await?;
+
+    if trader.activation() <= Utc::now() {
+        return Err(t!(
+            lang,
+            "remind.baro_here",
+            location = trader.location,
+            time = prefs.format_time(trader.expiry())
+        )
+        .into());
+    }
+
+    set_reminder(
+        ctx,
+        ReminderEvent::BaroArrival,
+        Some(trader.location),
+        trader.activation(),
+        minutes_before,
+        in_channel,
+    )
+    .await
+}
+
+/// Reminds you before the weekly Archon Hunt resets
+#[command(slash_command, rename = "archon-reset")]
+pub async fn archon_reset(
+    ctx: Context<'_>,
+    #[description = "How many minutes before the reset to remind you (default: 15)"]
+    #[max = 1440]
+    minutes_before: Option<u32>,
+    #[description = "Ping you in this channel instead of sending a DM"] in_channel: Option<bool>,
+) -> CmdRet {
+    let archon_hunt = ctx.data().worldstate().fetch::<ArchonHunt>().await?;
+
+    set_reminder(
+        ctx,
+        ReminderEvent::ArchonReset,
+        None,
+        archon_hunt.expiry(),
+        minutes_before,
+        in_channel,
+    )
+    .await
+}
+
+/// Lists your reminders
+#[command(slash_command)]
+pub async fn list(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+
+    let reminders = ctx
+        .db()
+        .get_reminders_for_user(ctx.author().id.get() as i64)
+        .await?;
+
+    if reminders.is_empty() {
+        ctx.send(
+            CreateReply::default()
+                .content(t!(lang, "remind.none"))
+                .ephemeral(true),
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let lines = reminders
+        .iter()
+        .map(|reminder| {
+            t!(
+                lang,
+                "remind.list_entry",
+                id = reminder.id,
+                event = reminder.describe(lang),
+                time = prefs.format_time(reminder.remind_at),
+                delivery = reminder.delivery(lang)
+            )
+        })
+        .collect::<Vec<_>>();
+
+    ctx.send(
+        CreateReply::default()
+            .embed(
+                prefs
+                    .embed()
+                    .title(t!(lang, "remind.list_title"))
+                    .description(lines.join("\n")),
+            )
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Cancels one of your reminders
+#[command(slash_command)]
+pub async fn cancel(
+    ctx: Context<'_>,
+    #[description = "The reminder to cancel"]
+    #[autocomplete = autocomplete_reminder]
+    reminder: i64,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+
+    if !ctx
+        .db()
+        .delete_reminder(reminder, ctx.author().id.get() as i64)
+        .await?
+    {
+        return Err(t!(lang, "remind.not_found").into());
+    }
+
+    ctx.send(
+        CreateReply::default()
+            .content(t!(lang, "remind.cancelled", id = reminder))
+            .ephemeral(true),
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,156 @@
+use std::future::Future;
+
+use chrono::{DateTime, Utc};
+use sqlx::SqlitePool;
+
+use crate::reminders::{Reminder, ReminderEvent};
+
+pub trait ReminderDbExtension {
+    /// Stores `reminder` and returns its id. The `id` of `reminder` is ignored.
+    fn save_reminder(
+        &self,
+        reminder: &Reminder,
+    ) -> impl Future<Output = Result<i64, sqlx::Error>> + Send;
+
+    fn get_reminders_for_user(
+        &self,
+        user_id: i64,
+    ) -> impl Future<Output = Result<Vec<Reminder>, sqlx::Error>> + Send;
+
+    /// Every reminder that is due at `now`.
+    fn get_due_reminders(
+        &self,
+        now: DateTime<Utc>,
+    ) -> impl Future<Output = Result<Vec<Reminder>, sqlx::Error>> + Send;
+
+    /// Deletes the reminder if it belongs to `user_id`. Returns whether it did.
+    fn delete_reminder(
+        &self,
+        id: i64,
+        user_id: i64,
+    ) -> impl Future<Output = Result<bool, sqlx::Error>> + Send;
+
+    /// Deletes the reminders that would be sent into a channel of the guild.
+    fn delete_channel_reminders(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl ReminderDbExtension for SqlitePool {
+    async fn save_reminder(&self, reminder: &Reminder) -> Result<i64, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let id = sqlx::query!(
+            "
+            INSERT INTO reminders (
+                user_id,
+                server_id,
+                channel_id,
+                event,
+                detail,
+                event_at,
+                remind_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7);
+            ",
+            reminder.user_id,
+            reminder.server_id,
+            reminder.channel_id,
+            reminder.event,
+            reminder.detail,
+            reminder.event_at,
+            reminder.remind_at
+        )
+        .execute(tx.as_mut())
+        .await?
+        .last_insert_rowid();
+
+        tx.commit().await?;
+
+        Ok(id)
+    }
+
+    async fn get_reminders_for_user(&self, user_id: i64) -> Result<Vec<Reminder>, sqlx::Error> {
+        sqlx::query_as!(
+            Reminder,
+            r#"
+            SELECT
+                id,
+                user_id,
+                server_id,
+                channel_id,
+                event as "event: ReminderEvent",
+                detail,
+                event_at as "event_at: DateTime<Utc>",
+                remind_at as "remind_at: DateTime<Utc>"
+            FROM reminders
+            WHERE user_id = $1
+            ORDER BY remind_at
+            "#,
+            user_id
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn get_due_reminders(&self, now: DateTime<Utc>) -> Result<Vec<Reminder>, sqlx::Error> {
+        sqlx::query_as!(
+            Reminder,
+            r#"
+            SELECT
+                id,
+                user_id,
+                server_id,
+                channel_id,
+                event as "event: ReminderEvent",
+                detail,
+                event_at as "event_at: DateTime<Utc>",
+                remind_at as "remind_at: DateTime<Utc>"
+            FROM reminders
+            WHERE remind_at <= $1
+            "#,
+            now
+        )
+        .fetch_all(self)
+        .await
+    }
+
+    async fn delete_reminder(&self, id: i64, user_id: i64) -> Result<bool, sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let deleted = sqlx::query!(
+            "
+            DELETE FROM reminders
+            WHERE id = $1 AND user_id = $2;
+            ",
+            id,
+            user_id
+        )
+        .execute(tx.as_mut())
+        .await?
+        .rows_affected();
+
+        tx.commit().await?;
+
+        Ok(deleted > 0)
+    }
+
+    async fn delete_channel_reminders(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM reminders
+            WHERE server_id = $1 AND channel_id IS NOT NULL;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
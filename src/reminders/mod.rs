@@ -0,0 +1,149 @@
+pub mod commands;
+pub mod db;
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use poise::serenity_prelude::{self, ChannelId, CreateMessage, Mentionable, UserId};
+
+use crate::{
+    AppData,
+    Error,
+    i18n::{Language, t},
+    reminders::db::ReminderDbExtension,
+    settings::Preferences,
+};
+
+/// How often the scheduler looks for due reminders.
+const SCHEDULER_INTERVAL: Duration = Duration::from_secs(30);
+
+/// What a reminder is set for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, sqlx::Type)]
+#[repr(i64)]
+pub enum ReminderEvent {
+    Arbitration,
+    CetusNight,
+    BaroArrival,
+    ArchonReset,
+}
+
+impl ReminderEvent {
+    pub fn title(self, lang: Language) -> &'static str {
+        match self {
+            Self::Arbitration => t!(lang, "remind.event.arbitration"),
+            Self::CetusNight => t!(lang, "remind.event.cetus_night"),
+            Self::BaroArrival => t!(lang, "remind.event.baro_arrival"),
+            Self::ArchonReset => t!(lang, "remind.event.archon_reset"),
+        }
+    }
+}
+
+/// A reminder set with `/remind`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Reminder {
+    pub id: i64,
+    pub user_id: i64,
+    /// The guild the reminder was set in, if any.
+    pub server_id: Option<i64>,
+    /// The channel to ping the user in, or `None` to send a DM.
+    pub channel_id: Option<i64>,
+    pub event: ReminderEvent,
+    /// E.g. the node of an Arbitration, in the language of the user who set the reminder.
+    pub detail: Option<String>,
+    pub event_at: DateTime<Utc>,
+    pub remind_at: DateTime<Utc>,
+}
+
+impl Reminder {
+    /// The event with its detail, e.g. `Arbitration (S Tier: Casta)`.
+    pub fn describe(&self, lang: Language) -> String {
+        match &self.detail {
+            Some(detail) => format!("{} ({detail})", self.event.title(lang)),
+            None => self.event.title(lang).to_owned(),
+        }
+    }
+
+    /// Where the reminder is sent: a channel mention or "via DM".
+    pub fn delivery(&self, lang: Language) -> String {
+        match self.channel_id {
+            Some(channel_id) => ChannelId::new(channel_id as u64).mention().to_string(),
+            None => t!(lang, "remind.via_dm").to_owned(),
+        }
+    }
+}
+
+/// Sends every due reminder and removes it afterwards.
+///
+/// Reminders for events that already happened (e.g. while the bot was offline) are removed
+/// without being sent.
+pub async fn run_scheduler(ctx: serenity_prelude::Context, data: AppData) {
+    let mut interval = tokio::time::interval(SCHEDULER_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let now = Utc::now();
+        let reminders = match data.db().get_due_reminders(now).await {
+            Ok(reminders) => reminders,
+            Err(e) => {
+                tracing::warn!(error = %e, "Could not fetch due reminders");
+                continue;
+            },
+        };
+
+        for reminder in reminders {
+            let sent = if reminder.event_at > now {
+                send_reminder(&ctx, &data, &reminder).await
+            } else {
+                Ok(())
+            };
+
+            // most likely the user has DMs disabled or the channel is gone
+            if let Err(e) = sent {
+                tracing::warn!(error = %e, reminder = reminder.id, "Could not send reminder");
+            }
+
+            if let Err(e) = data
+                .db()
+                .delete_reminder(reminder.id, reminder.user_id)
+                .await
+            {
+                tracing::warn!(error = %e, reminder = reminder.id, "Could not delete reminder");
+            }
+        }
+    }
+}
+
+async fn send_reminder(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    reminder: &Reminder,
+) -> Result<(), Error> {
+    let prefs = Preferences::resolve(data.db(), reminder.user_id, reminder.server_id, None).await?;
+    let lang = prefs.language;
+    let user = UserId::new(reminder.user_id as u64);
+
+    let text = t!(
+        lang,
+        "remind.message",
+        event = reminder.describe(lang),
+        time = prefs.format_time(reminder.event_at)
+    );
+
+    match reminder.channel_id {
+        Some(channel_id) => {
+            ChannelId::new(channel_id as u64)
+                .send_message(
+                    ctx,
+                    CreateMessage::new().content(format!("{} {text}", user.mention())),
+                )
+                .await?;
+        },
+        None => {
+            user.direct_message(ctx, CreateMessage::new().content(text))
+                .await?;
+        },
+    }
+
+    Ok(())
+}
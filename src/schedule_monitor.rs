@@ -0,0 +1,100 @@
+use std::{collections::HashSet, env, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use poise::serenity_prelude::{self, CreateMessage, UserId};
+
+use crate::{AppData, game_data::data_dir, i18n::t, settings::Preferences};
+
+const DEFAULT_WARNING_DAYS: i64 = 30;
+
+/// How often the remaining schedule is checked while it is still long enough.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60 * 24);
+
+/// How long before the end of the schedule owners are warned.
+///
+/// Set with `SCHEDULE_WARNING_DAYS`, defaults to 30 days.
+fn warning_period() -> TimeDelta {
+    let days = env::var("SCHEDULE_WARNING_DAYS")
+        .ok()
+        .and_then(|days| days.parse().ok())
+        .unwrap_or(DEFAULT_WARNING_DAYS);
+
+    TimeDelta::days(days)
+}
+
+/// Watches how far ahead the arbitration schedule reaches and warns the bot owners once it
+/// is about to run out. Warns again for every reload that does not fix it.
+pub async fn monitor_schedule(
+    ctx: serenity_prelude::Context,
+    data: AppData,
+    owners: HashSet<UserId>,
+) {
+    let mut reloads = data.subscribe_game_data();
+    let warning_period = warning_period();
+
+    loop {
+        let schedule_end = reloads.borrow_and_update().schedule_end();
+
+        if schedule_end.is_none_or(|end| end - Utc::now() <= warning_period) {
+            warn_owners(&ctx, &data, &owners, schedule_end).await;
+
+            if reloads.changed().await.is_err() {
+                return;
+            }
+            continue;
+        }
+
+        tracing::debug!(?schedule_end, "Arbitration schedule is long enough");
+
+        tokio::select! {
+            _ = tokio::time::sleep(CHECK_INTERVAL) => {},
+            changed = reloads.changed() => {
+                if changed.is_err() {
+                    return;
+                }
+            },
+        }
+    }
+}
+
+async fn warn_owners(
+    ctx: &serenity_prelude::Context,
+    data: &AppData,
+    owners: &HashSet<UserId>,
+    schedule_end: Option<DateTime<Utc>>,
+) {
+    tracing::warn!(?schedule_end, "Arbitration schedule is running out");
+
+    for owner in owners {
+        let prefs = Preferences::resolve(data.db(), owner.get() as i64, None, None)
+            .await
+            .unwrap_or_default();
+        let lang = prefs.language;
+
+        let description = match schedule_end {
+            Some(end) => t!(
+                lang,
+                "owner.schedule_running_out",
+                until = prefs.format_time(end),
+                dir = data_dir().display()
+            ),
+            None => t!(lang, "owner.schedule_exhausted", dir = data_dir().display()),
+        };
+
+        let result = owner
+            .direct_message(
+                ctx,
+                CreateMessage::new().embed(
+                    prefs
+                        .embed()
+                        .title(t!(lang, "owner.schedule_warning_title"))
+                        .description(description),
+                ),
+            )
+            .await;
+
+        if let Err(e) = result {
+            tracing::warn!(error = %e, owner = %owner, "Could not warn owner about the schedule");
+        }
+    }
+}
@@ -0,0 +1,499 @@
+use chrono::{TimeDelta, Utc};
+use chrono_tz::{TZ_VARIANTS, Tz};
+use poise::{CreateReply, command, serenity_prelude::AutocompleteChoice};
+
+use crate::{
+    CmdRet,
+    Context,
+    Error,
+    i18n::{Language, LocalizedChoice, t},
+    settings::{
+        Preferences,
+        db::SettingsDbExtension,
+        model::{GuildSettings, MarketDefault, TimeFormat, UserSettings},
+    },
+    utils::ContextExt,
+};
+
+#[command(
+    slash_command,
+    subcommands(
+        "show",
+        "language",
+        "color",
+        "timezone",
+        "time_format",
+        "ephemeral",
+        "emojis",
+        "market",
+        "reset"
+    ),
+    subcommand_required,
+    default_member_permissions = "ADMINISTRATOR",
+    guild_only
+)]
+pub async fn settings(_: Context<'_>) -> CmdRet {
+    Ok(())
+}
+
+/// Loads the guild's settings, applies `update` and saves them again.
+///
+/// Returns the guild's preferences, without the invoking admin's personal ones applied.
+async fn update_guild_settings(
+    ctx: Context<'_>,
+    update: impl FnOnce(&mut GuildSettings),
+) -> Result<Preferences, Error> {
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+
+    let mut settings = ctx
+        .db()
+        .get_guild_settings(guild_id)
+        .await?
+        .unwrap_or_else(|| GuildSettings::new(guild_id));
+
+    update(&mut settings);
+
+    ctx.db().save_guild_settings(&settings).await?;
+
+    Ok(Preferences::for_guild(ctx.db(), guild_id).await?)
+}
+
+async fn reply_saved(ctx: Context<'_>, prefs: Preferences, description: String) -> CmdRet {
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(prefs.language, "settings.saved"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+fn on_off(lang: Language, value: bool) -> &'static str {
+    if value {
+        t!(lang, "settings.on")
+    } else {
+        t!(lang, "settings.off")
+    }
+}
+
+/// Show the current settings of this server.
+#[command(slash_command)]
+pub async fn show(ctx: Context<'_>) -> CmdRet {
+    // the admin's own language, timezone and time format must not show up as the server's
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+    let prefs = Preferences::for_guild(ctx.db(), guild_id).await?;
+    let lang = prefs.language;
+
+    ctx.send(
+        CreateReply::default().reply(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "settings.title"))
+                .field(t!(lang, "settings.language"), lang.name_in(lang), true)
+                .field(
+                    t!(lang, "settings.color"),
+                    format!("`#{:06x}`", prefs.color),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.timezone"),
+                    prefs
+                        .timezone
+                        .map(|tz| format!("`{}`", tz.name()))
+                        .unwrap_or_else(|| t!(lang, "settings.not_set").to_owned()),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.time_format"),
+                    prefs.time_format.name_in(lang),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.ephemeral"),
+                    on_off(lang, prefs.ephemeral),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.emojis"),
+                    on_off(lang, prefs.custom_emojis),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.market_orders_limit"),
+                    format!("`{}`", prefs.market_orders_limit),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.market_ingame_only"),
+                    on_off(lang, prefs.market_ingame_only),
+                    true,
+                ),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the default language the bot uses in this server.
+#[command(slash_command)]
+pub async fn language(
+    ctx: Context<'_>,
+    #[description = "The language to use. Leave empty to use each user's Discord language."]
+    language: Option<Language>,
+) -> CmdRet {
+    let prefs = update_guild_settings(ctx, |settings| settings.language = language).await?;
+    let lang = prefs.language;
+
+    let description = match language {
+        Some(language) => {
+            t!(
+                lang,
+                "settings.guild_language_set",
+                language = language.name_in(lang)
+            )
+        },
+        None => t!(lang, "settings.guild_language_reset").to_owned(),
+    };
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Set the colour of the bot's embeds in this server.
+#[command(slash_command)]
+pub async fn color(
+    ctx: Context<'_>,
+    #[description = "A hex colour like #228b22. Leave empty to use the default colour."]
+    color: Option<String>,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+
+    let color = match color {
+        Some(color) => Some(
+            u32::from_str_radix(color.trim_start_matches('#'), 16)
+                .ok()
+                .filter(|color| *color <= 0xffffff)
+                .ok_or_else(|| t!(lang, "settings.invalid_color", color = color))?,
+        ),
+        None => None,
+    };
+
+    let prefs = update_guild_settings(ctx, |settings| settings.embed_color = color).await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.color_set",
+        color = format!("#{:06x}", prefs.color)
+    );
+
+    reply_saved(ctx, prefs, description).await
+}
+
+async fn autocomplete_timezone(_ctx: Context<'_>, query: &str) -> Vec<AutocompleteChoice> {
+    let query = query.to_lowercase();
+
+    TZ_VARIANTS
+        .iter()
+        .filter(|tz| tz.name().to_lowercase().contains(&query))
+        .take(25)
+        .map(|tz| AutocompleteChoice::new(tz.name(), tz.name()))
+        .collect()
+}
+
+/// Parses an IANA timezone name such as `Europe/Berlin`.
+pub(crate) fn parse_timezone(lang: Language, timezone: &str) -> Result<Tz, Error> {
+    timezone
+        .parse::<Tz>()
+        .map_err(|_| t!(lang, "settings.invalid_timezone", timezone = timezone).into())
+}
+
+/// Set the timezone used for absolute times in this server.
+#[command(slash_command)]
+pub async fn timezone(
+    ctx: Context<'_>,
+    #[description = "An IANA timezone like Europe/Berlin. Leave empty to unset it."]
+    #[autocomplete = autocomplete_timezone]
+    timezone: Option<String>,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+
+    let timezone = timezone
+        .map(|timezone| parse_timezone(lang, &timezone))
+        .transpose()?;
+
+    let prefs = update_guild_settings(ctx, |settings| {
+        settings.timezone = timezone.map(|tz| tz.name().to_owned())
+    })
+    .await?;
+    let lang = prefs.language;
+
+    let description = match timezone {
+        Some(tz) => t!(lang, "settings.timezone_set", timezone = tz.name()),
+        None => t!(lang, "settings.timezone_reset").to_owned(),
+    };
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Set whether times are shown as relative, absolute, or both in this server.
+#[command(slash_command, rename = "time-format")]
+pub async fn time_format(
+    ctx: Context<'_>,
+    #[description = "How to show times. Leave empty to use relative times."] format: Option<
+        TimeFormat,
+    >,
+) -> CmdRet {
+    let prefs = update_guild_settings(ctx, |settings| settings.time_format = format).await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.time_format_set",
+        example = prefs.format_time(Utc::now() + TimeDelta::hours(6))
+    );
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Set whether the bot's replies are only visible to the user who used the command.
+#[command(slash_command)]
+pub async fn ephemeral(
+    ctx: Context<'_>,
+    #[description = "Whether replies should be ephemeral."] enabled: bool,
+) -> CmdRet {
+    let prefs = update_guild_settings(ctx, |settings| settings.ephemeral = Some(enabled)).await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.ephemeral_set",
+        value = on_off(prefs.language, enabled)
+    );
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Set whether the bot uses its custom emojis, e.g. for platinum.
+#[command(slash_command)]
+pub async fn emojis(
+    ctx: Context<'_>,
+    #[description = "Whether to use custom emojis. Turn this off if they don't render."]
+    enabled: bool,
+) -> CmdRet {
+    let prefs =
+        update_guild_settings(ctx, |settings| settings.custom_emojis = Some(enabled)).await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.emojis_set",
+        value = on_off(prefs.language, enabled)
+    );
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Set the defaults of the market commands in this server.
+#[command(slash_command)]
+pub async fn market(
+    ctx: Context<'_>,
+    #[description = "The default number of orders `/market orders` returns."]
+    #[min = 1]
+    #[max = 100]
+    orders_limit: Option<u32>,
+    #[description = "Whether `/market orders` only shows users that are ingame by default."]
+    ingame_only: Option<bool>,
+    #[description = "A default to reset to the bot's default."] reset: Option<MarketDefault>,
+) -> CmdRet {
+    let prefs = update_guild_settings(ctx, |settings| {
+        settings.market_orders_limit = orders_limit.or(settings.market_orders_limit);
+        settings.market_ingame_only = ingame_only.or(settings.market_ingame_only);
+
+        match reset {
+            Some(MarketDefault::OrdersLimit) => settings.market_orders_limit = None,
+            Some(MarketDefault::IngameOnly) => settings.market_ingame_only = None,
+            Some(MarketDefault::Both) => {
+                settings.market_orders_limit = None;
+                settings.market_ingame_only = None;
+            },
+            None => {},
+        }
+    })
+    .await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.market_set",
+        limit = prefs.market_orders_limit,
+        ingame_only = on_off(prefs.language, prefs.market_ingame_only)
+    );
+
+    reply_saved(ctx, prefs, description).await
+}
+
+/// Reset all settings of this server to the defaults.
+#[command(slash_command)]
+pub async fn reset(ctx: Context<'_>) -> CmdRet {
+    let guild_id = ctx.guild_id().unwrap().get() as i64;
+
+    ctx.db().delete_guild_settings(guild_id).await?;
+
+    let prefs = Preferences::for_guild(ctx.db(), guild_id).await?;
+    let description = t!(prefs.language, "settings.reset").to_owned();
+
+    reply_saved(ctx, prefs, description).await
+}
+
+#[command(
+    slash_command,
+    subcommands("user_show", "user_language", "user_timezone", "user_time_format"),
+    subcommand_required
+)]
+pub async fn preferences(_: Context<'_>) -> CmdRet {
+    Ok(())
+}
+
+/// Loads the invoking user's settings, applies `update` and saves them again.
+async fn update_user_settings(
+    ctx: Context<'_>,
+    update: impl FnOnce(&mut UserSettings),
+) -> Result<Preferences, Error> {
+    let user_id = ctx.author().id.get() as i64;
+
+    let mut settings = ctx
+        .db()
+        .get_user_settings(user_id)
+        .await?
+        .unwrap_or_else(|| UserSettings::new(user_id));
+
+    update(&mut settings);
+
+    ctx.db().save_user_settings(&settings).await?;
+
+    Ok(ctx.preferences().await?)
+}
+
+async fn reply_saved_privately(
+    ctx: Context<'_>,
+    prefs: Preferences,
+    description: String,
+) -> CmdRet {
+    ctx.send(
+        CreateReply::default().reply(true).ephemeral(true).embed(
+            prefs
+                .embed()
+                .title(t!(prefs.language, "settings.saved"))
+                .description(description),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Show your personal settings.
+#[command(slash_command, rename = "show")]
+pub async fn user_show(ctx: Context<'_>) -> CmdRet {
+    let prefs = ctx.preferences().await?;
+    let lang = prefs.language;
+
+    ctx.send(
+        CreateReply::default().reply(true).ephemeral(true).embed(
+            prefs
+                .embed()
+                .title(t!(lang, "settings.user_title"))
+                .field(t!(lang, "settings.language"), lang.name_in(lang), true)
+                .field(
+                    t!(lang, "settings.timezone"),
+                    prefs
+                        .timezone
+                        .map(|tz| format!("`{}`", tz.name()))
+                        .unwrap_or_else(|| t!(lang, "settings.not_set").to_owned()),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.time_format"),
+                    prefs.time_format.name_in(lang),
+                    true,
+                )
+                .field(
+                    t!(lang, "settings.example"),
+                    prefs.format_time(Utc::now() + TimeDelta::hours(6)),
+                    false,
+                ),
+        ),
+    )
+    .await?;
+
+    Ok(())
+}
+
+/// Set the language the bot uses when answering you, regardless of the server.
+#[command(slash_command, rename = "language")]
+pub async fn user_language(
+    ctx: Context<'_>,
+    #[description = "The language to use. Leave empty to use the server's language."]
+    language: Option<Language>,
+) -> CmdRet {
+    let prefs = update_user_settings(ctx, |settings| settings.language = language).await?;
+    let lang = prefs.language;
+
+    let description = match language {
+        Some(language) => t!(
+            lang,
+            "settings.user_language_set",
+            language = language.name_in(lang)
+        ),
+        None => t!(lang, "settings.user_language_reset").to_owned(),
+    };
+
+    reply_saved_privately(ctx, prefs, description).await
+}
+
+/// Set your timezone, used for absolute times.
+#[command(slash_command, rename = "timezone")]
+pub async fn user_timezone(
+    ctx: Context<'_>,
+    #[description = "An IANA timezone like Europe/Berlin. Leave empty to use the server's."]
+    #[autocomplete = autocomplete_timezone]
+    timezone: Option<String>,
+) -> CmdRet {
+    let lang = ctx.preferences().await?.language;
+
+    let timezone = timezone
+        .map(|timezone| parse_timezone(lang, &timezone))
+        .transpose()?;
+
+    let prefs = update_user_settings(ctx, |settings| {
+        settings.timezone = timezone.map(|tz| tz.name().to_owned())
+    })
+    .await?;
+    let lang = prefs.language;
+
+    let description = match timezone {
+        Some(tz) => t!(lang, "settings.user_timezone_set", timezone = tz.name()),
+        None => t!(lang, "settings.user_timezone_reset").to_owned(),
+    };
+
+    reply_saved_privately(ctx, prefs, description).await
+}
+
+/// Set whether times are shown as relative, absolute, or both.
+#[command(slash_command, rename = "time-format")]
+pub async fn user_time_format(
+    ctx: Context<'_>,
+    #[description = "How to show times. Leave empty to use the server's format."] format: Option<
+        TimeFormat,
+    >,
+) -> CmdRet {
+    let prefs = update_user_settings(ctx, |settings| settings.time_format = format).await?;
+
+    let description = t!(
+        prefs.language,
+        "settings.time_format_set",
+        example = prefs.format_time(Utc::now() + TimeDelta::hours(6))
+    );
+
+    reply_saved_privately(ctx, prefs, description).await
+}
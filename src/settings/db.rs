@@ -0,0 +1,180 @@
+use std::future::Future;
+
+use chrono::Utc;
+use sqlx::SqlitePool;
+
+use crate::{
+    i18n::Language,
+    settings::model::{GuildSettings, TimeFormat, UserSettings},
+};
+
+pub trait SettingsDbExtension {
+    fn get_guild_settings(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<Option<GuildSettings>, sqlx::Error>> + Send;
+
+    fn get_user_settings(
+        &self,
+        user_id: i64,
+    ) -> impl Future<Output = Result<Option<UserSettings>, sqlx::Error>> + Send;
+
+    fn save_guild_settings(
+        &self,
+        settings: &GuildSettings,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn save_user_settings(
+        &self,
+        settings: &UserSettings,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+
+    fn delete_guild_settings(
+        &self,
+        guild_id: i64,
+    ) -> impl Future<Output = Result<(), sqlx::Error>> + Send;
+}
+
+impl SettingsDbExtension for SqlitePool {
+    async fn get_guild_settings(
+        &self,
+        guild_id: i64,
+    ) -> Result<Option<GuildSettings>, sqlx::Error> {
+        sqlx::query_as!(
+            GuildSettings,
+            r#"
+            SELECT
+                server_id,
+                language as "language: Language",
+                embed_color as "embed_color: u32",
+                timezone,
+                time_format as "time_format: TimeFormat",
+                ephemeral,
+                custom_emojis,
+                market_orders_limit as "market_orders_limit: u32",
+                market_ingame_only,
+                created_at as "created_at: chrono::DateTime<Utc>",
+                modified_at as "modified_at: chrono::DateTime<Utc>"
+            FROM guild_settings
+            WHERE server_id = $1
+            "#,
+            guild_id
+        )
+        .fetch_optional(self)
+        .await
+    }
+
+    async fn get_user_settings(&self, user_id: i64) -> Result<Option<UserSettings>, sqlx::Error> {
+        sqlx::query_as!(
+            UserSettings,
+            r#"
+            SELECT
+                user_id,
+                language as "language: Language",
+                timezone,
+                time_format as "time_format: TimeFormat",
+                created_at as "created_at: chrono::DateTime<Utc>",
+                modified_at as "modified_at: chrono::DateTime<Utc>"
+            FROM user_settings
+            WHERE user_id = $1
+            "#,
+            user_id
+        )
+        .fetch_optional(self)
+        .await
+    }
+
+    async fn save_guild_settings(&self, settings: &GuildSettings) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        let server_id = settings.server_id.get() as i64;
+
+        sqlx::query!(
+            "
+            INSERT INTO guild_settings (
+                server_id,
+                language,
+                embed_color,
+                timezone,
+                ephemeral,
+                custom_emojis,
+                market_orders_limit,
+                market_ingame_only,
+                time_format
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (server_id)
+            DO UPDATE
+            SET modified_at = CURRENT_TIMESTAMP,
+                language = $2,
+                embed_color = $3,
+                timezone = $4,
+                ephemeral = $5,
+                custom_emojis = $6,
+                market_orders_limit = $7,
+                market_ingame_only = $8,
+                time_format = $9;
+            ",
+            server_id,
+            settings.language,
+            settings.embed_color,
+            settings.timezone,
+            settings.ephemeral,
+            settings.custom_emojis,
+            settings.market_orders_limit,
+            settings.market_ingame_only,
+            settings.time_format
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn save_user_settings(&self, settings: &UserSettings) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            INSERT INTO user_settings (user_id, language, timezone, time_format)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id)
+            DO UPDATE
+            SET modified_at = CURRENT_TIMESTAMP,
+                language = $2,
+                timezone = $3,
+                time_format = $4;
+            ",
+            settings.user_id,
+            settings.language,
+            settings.timezone,
+            settings.time_format
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+
+    async fn delete_guild_settings(&self, guild_id: i64) -> Result<(), sqlx::Error> {
+        let mut tx = self.begin().await?;
+
+        sqlx::query!(
+            "
+            DELETE FROM guild_settings
+            WHERE server_id = $1;
+            ",
+            guild_id
+        )
+        .execute(tx.as_mut())
+        .await?;
+
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,218 @@
+pub mod commands;
+pub mod db;
+pub mod model;
+
+use chrono::{DateTime, Datelike, NaiveDate, TimeDelta, Utc, Weekday};
+use chrono_tz::Tz;
+use poise::serenity_prelude::{CreateEmbed, FormattedTimestampStyle};
+use sqlx::SqlitePool;
+
+use crate::{
+    DEFAULT_COLOR,
+    emojis,
+    i18n::{Language, t},
+    settings::{
+        db::SettingsDbExtension,
+        model::{GuildSettings, TimeFormat, UserSettings},
+    },
+    utils::{self, to_timestamp},
+};
+
+pub const DEFAULT_ORDERS_LIMIT: usize = 20;
+
+/// The effective settings for a single reply, after merging user, guild and default values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Preferences {
+    pub language: Language,
+    pub color: u32,
+    pub timezone: Option<Tz>,
+    pub time_format: TimeFormat,
+    pub ephemeral: bool,
+    pub custom_emojis: bool,
+    pub market_orders_limit: usize,
+    pub market_ingame_only: bool,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            language: Language::default(),
+            color: DEFAULT_COLOR,
+            timezone: None,
+            time_format: TimeFormat::default(),
+            ephemeral: false,
+            custom_emojis: true,
+            market_orders_limit: DEFAULT_ORDERS_LIMIT,
+            market_ingame_only: true,
+        }
+    }
+}
+
+impl Preferences {
+    /// Resolves the preferences for a user, optionally inside a guild.
+    ///
+    /// User settings take precedence over guild settings, which take precedence over the
+    /// Discord client locale.
+    pub async fn resolve(
+        db: &SqlitePool,
+        user_id: i64,
+        guild_id: Option<i64>,
+        locale: Option<&str>,
+    ) -> Result<Self, sqlx::Error> {
+        let user = db.get_user_settings(user_id).await?;
+        let guild = match guild_id {
+            Some(guild_id) => db.get_guild_settings(guild_id).await?,
+            None => None,
+        };
+
+        let mut preferences = Self::default().with_locale(locale);
+
+        if let Some(guild) = guild {
+            preferences = preferences.with_guild(guild);
+        }
+
+        if let Some(user) = user {
+            preferences = preferences.with_user(user);
+        }
+
+        Ok(preferences)
+    }
+
+    /// Resolves the preferences used for notifications sent into a guild.
+    pub async fn for_guild(db: &SqlitePool, guild_id: i64) -> Result<Self, sqlx::Error> {
+        let guild = db.get_guild_settings(guild_id).await?;
+
+        Ok(match guild {
+            Some(guild) => Self::default().with_guild(guild),
+            None => Self::default(),
+        })
+    }
+
+    fn with_locale(mut self, locale: Option<&str>) -> Self {
+        if let Some(language) = locale.and_then(Language::from_locale) {
+            self.language = language;
+        }
+
+        self
+    }
+
+    fn with_guild(self, guild: GuildSettings) -> Self {
+        Self {
+            language: guild.language.unwrap_or(self.language),
+            color: guild.embed_color.unwrap_or(self.color),
+            timezone: guild
+                .timezone
+                .and_then(|tz| tz.parse().ok())
+                .or(self.timezone),
+            time_format: guild.time_format.unwrap_or(self.time_format),
+            ephemeral: guild.ephemeral.unwrap_or(self.ephemeral),
+            custom_emojis: guild.custom_emojis.unwrap_or(self.custom_emojis),
+            market_orders_limit: guild
+                .market_orders_limit
+                .map(|limit| limit as usize)
+                .unwrap_or(self.market_orders_limit),
+            market_ingame_only: guild.market_ingame_only.unwrap_or(self.market_ingame_only),
+        }
+    }
+
+    fn with_user(self, user: UserSettings) -> Self {
+        Self {
+            language: user.language.unwrap_or(self.language),
+            timezone: user
+                .timezone
+                .and_then(|tz| tz.parse().ok())
+                .or(self.timezone),
+            time_format: user.time_format.unwrap_or(self.time_format),
+            ..self
+        }
+    }
+
+    /// Formats a point in time according to the configured [`TimeFormat`].
+    ///
+    /// Absolute times use the configured timezone. Without one, Discord renders them in the
+    /// viewer's local time.
+    pub fn format_time(&self, time: DateTime<Utc>) -> String {
+        let absolute = match self.timezone {
+            Some(tz) => format!("`{}`", time.with_timezone(&tz).format("%Y-%m-%d %H:%M %Z")),
+            None => to_timestamp(time, FormattedTimestampStyle::ShortDateTime)
+                .map(|timestamp| timestamp.to_string())
+                .unwrap_or_default(),
+        };
+        let relative = to_timestamp(time, FormattedTimestampStyle::RelativeTime)
+            .map(|timestamp| timestamp.to_string())
+            .unwrap_or_default();
+
+        match self.time_format {
+            TimeFormat::Relative => relative,
+            TimeFormat::Absolute => absolute,
+            TimeFormat::Both => format!("{absolute} ({relative})"),
+        }
+    }
+
+    /// Formats a duration as days and hours, e.g. `2d 5h`.
+    pub fn format_duration(&self, duration: TimeDelta) -> String {
+        t!(
+            self.language,
+            "time.duration",
+            days = duration.num_days(),
+            hours = duration.num_hours() % 24
+        )
+    }
+
+    /// The calendar day `time` falls on in the configured timezone, or UTC.
+    pub fn local_date(&self, time: DateTime<Utc>) -> NaiveDate {
+        match self.timezone {
+            Some(tz) => time.with_timezone(&tz).date_naive(),
+            None => time.date_naive(),
+        }
+    }
+
+    /// A heading such as `Monday, 2026-10-19` for grouping rows by day.
+    pub fn day_heading(&self, date: NaiveDate) -> String {
+        let lang = self.language;
+        let weekday = match date.weekday() {
+            Weekday::Mon => t!(lang, "time.monday"),
+            Weekday::Tue => t!(lang, "time.tuesday"),
+            Weekday::Wed => t!(lang, "time.wednesday"),
+            Weekday::Thu => t!(lang, "time.thursday"),
+            Weekday::Fri => t!(lang, "time.friday"),
+            Weekday::Sat => t!(lang, "time.saturday"),
+            Weekday::Sun => t!(lang, "time.sunday"),
+        };
+
+        format!("{weekday}, {}", date.format("%Y-%m-%d"))
+    }
+
+    /// A time in the configured timezone, or UTC, for text Discord renders no timestamps in,
+    /// such as autocomplete choices.
+    pub fn plain_time(&self, time: DateTime<Utc>) -> String {
+        let local = time.with_timezone(&self.timezone.unwrap_or(Tz::UTC));
+
+        format!(
+            "{} {}",
+            self.day_heading(local.date_naive()),
+            local.format("%H:%M %Z")
+        )
+    }
+
+    /// The "default embed" of [`utils::embed`] in the configured colour.
+    pub fn embed(&self) -> CreateEmbed {
+        utils::embed().color(self.color)
+    }
+
+    pub fn platinum(&self) -> &'static str {
+        if self.custom_emojis {
+            emojis::PLATINUM
+        } else {
+            emojis::PLATINUM_PLAIN
+        }
+    }
+
+    pub fn credits(&self) -> &'static str {
+        if self.custom_emojis {
+            emojis::CREDITS
+        } else {
+            emojis::CREDITS_PLAIN
+        }
+    }
+}
@@ -0,0 +1,102 @@
+use chrono::Utc;
+
+use crate::{i18n::Language, notifier::model::ServerId};
+
+/// How points in time are shown in embeds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, sqlx::Type, poise::ChoiceParameter)]
+#[sqlx(rename_all = "lowercase")]
+pub enum TimeFormat {
+    #[default]
+    #[name = "Relative (in 6 hours)"]
+    #[name_localized("de", "Relativ (in 6 Stunden)")]
+    #[name_localized("fr", "Relatif (dans 6 heures)")]
+    Relative,
+
+    #[name = "Absolute (2026-10-18 20:00)"]
+    #[name_localized("de", "Absolut (2026-10-18 20:00)")]
+    #[name_localized("fr", "Absolu (2026-10-18 20:00)")]
+    Absolute,
+
+    #[name = "Both"]
+    #[name_localized("de", "Beides")]
+    #[name_localized("fr", "Les deux")]
+    Both,
+}
+
+/// The market defaults `/settings market` can reset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum MarketDefault {
+    #[name = "Orders limit"]
+    #[name_localized("de", "Auftragslimit")]
+    #[name_localized("fr", "Limite d'ordres")]
+    OrdersLimit,
+
+    #[name = "Ingame only"]
+    #[name_localized("de", "Nur im Spiel")]
+    #[name_localized("fr", "En jeu uniquement")]
+    IngameOnly,
+
+    #[name = "Both"]
+    #[name_localized("de", "Beides")]
+    #[name_localized("fr", "Les deux")]
+    Both,
+}
+
+/// Per-guild overrides. `None` means the bot's default is used.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuildSettings {
+    pub server_id: ServerId,
+    pub language: Option<Language>,
+    pub embed_color: Option<u32>,
+    pub timezone: Option<String>,
+    pub time_format: Option<TimeFormat>,
+    pub ephemeral: Option<bool>,
+    pub custom_emojis: Option<bool>,
+    pub market_orders_limit: Option<u32>,
+    pub market_ingame_only: Option<bool>,
+    pub created_at: chrono::DateTime<Utc>,
+    pub modified_at: Option<chrono::DateTime<Utc>>,
+}
+
+impl GuildSettings {
+    /// Settings for a guild that has not changed anything yet.
+    pub fn new(server_id: i64) -> Self {
+        Self {
+            server_id: server_id.into(),
+            language: None,
+            embed_color: None,
+            timezone: None,
+            time_format: None,
+            ephemeral: None,
+            custom_emojis: None,
+            market_orders_limit: None,
+            market_ingame_only: None,
+            created_at: Utc::now(),
+            modified_at: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UserSettings {
+    pub user_id: i64,
+    pub language: Option<Language>,
+    pub timezone: Option<String>,
+    pub time_format: Option<TimeFormat>,
+    pub created_at: chrono::DateTime<Utc>,
+    pub modified_at: Option<chrono::DateTime<Utc>>,
+}
+
+impl UserSettings {
+    /// Settings for a user that has not changed anything yet.
+    pub fn new(user_id: i64) -> Self {
+        Self {
+            user_id,
+            language: None,
+            timezone: None,
+            time_format: None,
+            created_at: Utc::now(),
+            modified_at: None,
+        }
+    }
+}
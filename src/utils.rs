@@ -14,6 +14,7 @@ use crate::{
     Context,
     DEFAULT_COLOR,
     notifier::model::{RoleIdToMention, ServerSubscription, SubscriptionType},
+    settings::Preferences,
 };
 
 pub fn to_timestamp(
@@ -65,6 +66,9 @@ impl<T> ApplyIf for T {
 pub trait ContextExt {
     fn db(&self) -> &SqlitePool;
     fn db_owned(&self) -> SqlitePool;
+
+    /// Resolves the [`Preferences`] of the invoking user in the current guild.
+    fn preferences(&self) -> impl Future<Output = Result<Preferences, sqlx::Error>> + Send;
 }
 
 impl ContextExt for Context<'_> {
@@ -75,6 +79,16 @@ impl ContextExt for Context<'_> {
     fn db_owned(&self) -> SqlitePool {
         self.data().db_owned()
     }
+
+    async fn preferences(&self) -> Result<Preferences, sqlx::Error> {
+        Preferences::resolve(
+            self.db(),
+            self.author().id.get() as i64,
+            self.guild_id().map(|id| id.get() as i64),
+            self.locale(),
+        )
+        .await
+    }
 }
 
 pub trait DbExtension {